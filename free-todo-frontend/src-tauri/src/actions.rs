@@ -0,0 +1,78 @@
+//! Shared action dispatch for global shortcuts and tray menu items.
+//!
+//! Both `shortcut.rs`'s config-driven registry and `tray.rs`'s menu handler
+//! need to run the exact same window/recording/screenshot actions; this
+//! module gives them one place to do it instead of each reimplementing
+//! "toggle the main window" separately.
+
+use log::info;
+use serde::Deserialize;
+use tauri::AppHandle;
+
+/// A named action that can be bound to a global shortcut or a tray menu item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    ToggleWindow,
+    ShowWindow,
+    HideWindow,
+    StartRecording,
+    StopRecording,
+    TakeScreenshot,
+    ViewScreenshots,
+}
+
+impl ShortcutAction {
+    /// Parse an action name as it appears in `lifetrace.toml`'s `[shortcuts]`
+    /// table or a tray menu item id.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "toggle_window" => Some(Self::ToggleWindow),
+            "show_window" => Some(Self::ShowWindow),
+            "hide_window" => Some(Self::HideWindow),
+            "start_recording" => Some(Self::StartRecording),
+            "stop_recording" => Some(Self::StopRecording),
+            "take_screenshot" => Some(Self::TakeScreenshot),
+            "view_screenshots" => Some(Self::ViewScreenshots),
+            _ => None,
+        }
+    }
+}
+
+/// Run `action` against the running app. Safe to call from a shortcut
+/// handler, a tray menu callback, or anywhere else that needs the same
+/// behavior.
+pub fn dispatch(app: &AppHandle, action: ShortcutAction) {
+    match action {
+        ShortcutAction::ToggleWindow => crate::visibility::toggle(app),
+        ShortcutAction::ShowWindow => crate::visibility::show(app),
+        ShortcutAction::HideWindow => crate::visibility::hide(app),
+        ShortcutAction::StartRecording => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = crate::recording::start_recording(&app).await {
+                    log::error!("Failed to start recording: {}", err);
+                }
+            });
+        }
+        ShortcutAction::StopRecording => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = crate::recording::stop_recording(&app).await {
+                    log::error!("Failed to stop recording: {}", err);
+                }
+            });
+        }
+        ShortcutAction::TakeScreenshot => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(err) = crate::recording::take_screenshot(&app).await {
+                    log::error!("Failed to take screenshot: {}", err);
+                }
+            });
+        }
+        ShortcutAction::ViewScreenshots => {
+            info!("View screenshots - feature not yet implemented");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Launch-on-login integration.
+//!
+//! Wraps the `auto-launch` crate behind the same get/set-and-persist shape as
+//! `shortcut::update_shortcut`: the OS-level registration is applied first,
+//! and the `start_on_login` config flag is only persisted once that succeeds.
+
+use auto_launch::{AutoLaunch, AutoLaunchBuilder};
+use log::{error, info};
+use tauri::AppHandle;
+
+const APP_NAME: &str = "FreeTodo";
+
+fn build() -> Result<AutoLaunch, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to resolve current executable path: {}", e))?;
+    let exe_path = exe_path
+        .to_str()
+        .ok_or_else(|| "Executable path is not valid UTF-8".to_string())?;
+
+    AutoLaunchBuilder::new()
+        .set_app_name(APP_NAME)
+        .set_app_path(exe_path)
+        .set_args(&[])
+        .build()
+        .map_err(|e| format!("Failed to build auto-launch handle: {}", e))
+}
+
+/// Apply `enabled` to the OS-level auto-launch registration, without
+/// touching the persisted config. Called once at startup to bring the OS
+/// state in line with whatever was loaded from `lifetrace.toml`.
+pub fn sync_with_config(enabled: bool) -> Result<(), String> {
+    let auto = build()?;
+    if enabled {
+        auto.enable()
+            .map_err(|e| format!("Failed to enable launch on login: {}", e))?;
+        info!("Launch on login enabled");
+    } else {
+        auto.disable()
+            .map_err(|e| format!("Failed to disable launch on login: {}", e))?;
+        info!("Launch on login disabled");
+    }
+    Ok(())
+}
+
+/// Whether launch on login is enabled in the persisted config.
+#[tauri::command]
+pub fn get_start_on_login() -> bool {
+    crate::config::current().start_on_login
+}
+
+/// Toggle launch-on-login: update the OS-level registration first, and only
+/// persist the flag once that succeeds.
+#[tauri::command]
+pub fn set_start_on_login(app: AppHandle, enabled: bool) -> Result<(), String> {
+    if let Err(err) = sync_with_config(enabled) {
+        error!("Failed to set start on login: {}", err);
+        return Err(err);
+    }
+    crate::config::persist_start_on_login(&app, enabled)
+}
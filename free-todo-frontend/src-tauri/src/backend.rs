@@ -3,15 +3,18 @@
 //! This module handles the lifecycle of the Python backend server,
 //! including starting, health checking, proxying, and stopping the process.
 
-use crate::backend_log::{emit_backend_log, format_download_progress, spawn_log_reader};
+use crate::backend_log::{
+    emit_backend_log, emit_labeled_log, format_download_progress, spawn_log_reader,
+};
 use crate::backend_paths::{
     get_backend_path, get_backend_script_entry, get_backend_script_root, get_data_dir,
-    get_requirements_path, get_runtime_root,
+    get_proxy_port, get_requirements_path, get_runtime_root,
 };
-use crate::backend_proxy::{start_proxy_server, ProxyState};
+use crate::backend_proxy::{start_proxy_server, ProxyState, WorkerHandle};
 use crate::backend_python::{
-    ensure_uv, ensure_uv_binary_with_progress, ensure_uv_python, ensure_uv_venv, ensure_venv,
-    find_python312, install_requirements, uv_env_pairs,
+    ensure_python_distribution_with_progress, ensure_uv, ensure_uv_binary_with_progress,
+    ensure_uv_python, ensure_uv_venv, ensure_venv, find_python312, install_requirements,
+    uv_env_pairs,
 };
 use crate::backend_support::{
     check_backend_health as check_backend_health_with_timeout, detect_running_backend_port,
@@ -26,27 +29,71 @@ use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 use tauri::AppHandle;
 
+/// Owns a spawned backend `Child` and kills it on drop, so a panic or an
+/// early return that skips `stop_managed_worker`/`stop_backend` can't leak
+/// the process past the app's own lifetime.
+struct KillOnDrop(Child);
+
+impl std::ops::Deref for KillOnDrop {
+    type Target = Child;
+    fn deref(&self) -> &Child {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for KillOnDrop {
+    fn deref_mut(&mut self) -> &mut Child {
+        &mut self.0
+    }
+}
+
+impl Drop for KillOnDrop {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+    }
+}
+
+/// One pooled backend worker: a port/readiness pair shared with the proxy,
+/// plus the `Child` its own supervisor task owns.
+struct WorkerSlot {
+    handle: WorkerHandle,
+    process: Mutex<Option<KillOnDrop>>,
+}
+
+/// Consecutive start failures a worker tolerates before its supervisor
+/// gives up and surfaces a fatal error instead of retrying forever.
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
 struct BackendState {
-    backend_port: Arc<AtomicU16>,
-    ready: Arc<AtomicBool>,
+    workers: Vec<WorkerSlot>,
     proxy_port: AtomicU16,
     stopping: AtomicBool,
     proxy_started: AtomicBool,
-    process: Mutex<Option<Child>>,
     uv_synced: AtomicBool,
 }
 
 static STATE: OnceLock<BackendState> = OnceLock::new();
 
+/// Number of pooled backend workers, resolved once from config at first use.
+fn worker_count() -> usize {
+    config::current().backend_workers.max(1)
+}
+
 fn state() -> &'static BackendState {
-    STATE.get_or_init(|| BackendState {
-        backend_port: Arc::new(AtomicU16::new(0)),
-        ready: Arc::new(AtomicBool::new(false)),
-        proxy_port: AtomicU16::new(0),
-        stopping: AtomicBool::new(false),
-        proxy_started: AtomicBool::new(false),
-        process: Mutex::new(None),
-        uv_synced: AtomicBool::new(false),
+    STATE.get_or_init(|| {
+        let workers = (0..worker_count())
+            .map(|_| WorkerSlot {
+                handle: WorkerHandle::new(),
+                process: Mutex::new(None),
+            })
+            .collect();
+        BackendState {
+            workers,
+            proxy_port: AtomicU16::new(0),
+            stopping: AtomicBool::new(false),
+            proxy_started: AtomicBool::new(false),
+            uv_synced: AtomicBool::new(false),
+        }
     })
 }
 
@@ -58,19 +105,17 @@ enum BackendRuntime {
     PyInstaller,
 }
 
-/// Determine backend runtime from env or build-time default
+/// Determine backend runtime: the persisted setup-wizard selection (which
+/// already folds in the `FREETODO_BACKEND_RUNTIME` env var, see
+/// `Config::from_layers`) takes priority over re-deciding on every boot,
+/// falling back to the build-time default.
 fn get_backend_runtime() -> BackendRuntime {
-    if let Ok(value) = std::env::var("FREETODO_BACKEND_RUNTIME") {
-        let normalized = value.to_lowercase();
-        if normalized == "uv" || normalized == "uv-run" || normalized == "uvrun" {
-            return BackendRuntime::Uv;
-        }
-        if normalized == "pyinstaller" {
-            return BackendRuntime::PyInstaller;
-        }
-        if normalized == "script" {
-            return BackendRuntime::Script;
-        }
+    if let Some(kind) = config::current().backend_runtime {
+        return match kind {
+            config::BackendRuntimeKind::Uv => BackendRuntime::Uv,
+            config::BackendRuntimeKind::Script => BackendRuntime::Script,
+            config::BackendRuntimeKind::Pyinstaller => BackendRuntime::PyInstaller,
+        };
     }
 
     if let Some(value) = option_env!("FREETODO_BACKEND_RUNTIME") {
@@ -124,15 +169,44 @@ fn mode_label(mode: ServerMode) -> &'static str {
 
 const BACKEND_LOG_LABEL: &str = "backend";
 
-/// Get the backend URL (proxy port)
-pub fn get_backend_url() -> String {
+/// Whether at least one pooled backend worker has most recently answered a
+/// health probe successfully. Used by `backend_tunnel` to reject remote
+/// traffic until the backend is actually up.
+pub fn is_ready() -> bool {
+    state()
+        .workers
+        .iter()
+        .any(|worker| worker.handle.ready.load(Ordering::Relaxed))
+}
+
+/// Ports of every pooled worker that has most recently answered a health
+/// probe successfully. Used by `recording` to fan a start/stop command out
+/// to the whole pool: the proxy round-robins ordinary traffic across
+/// workers, so a single request would only ever reach one of them.
+pub fn ready_worker_ports() -> Vec<u16> {
+    state()
+        .workers
+        .iter()
+        .filter(|worker| worker.handle.ready.load(Ordering::Relaxed))
+        .map(|worker| worker.handle.backend_port.load(Ordering::Relaxed))
+        .filter(|&port| port != 0)
+        .collect()
+}
+
+/// Get the backend port the proxy is actually listening on, falling back to
+/// the layered [`config::Config`] port if the proxy hasn't bound one yet.
+pub fn get_backend_port() -> u16 {
     let port = state().proxy_port.load(Ordering::Relaxed);
-    let port = if port == 0 {
-        config::ports::backend_port(server_mode())
+    if port == 0 {
+        config::current().backend_port
     } else {
         port
-    };
-    format!("http://127.0.0.1:{}", port)
+    }
+}
+
+/// Get the backend URL (proxy port)
+pub fn get_backend_url() -> String {
+    format!("http://127.0.0.1:{}", get_backend_port())
 }
 
 /// Check backend health
@@ -148,15 +222,18 @@ pub async fn start_backend(
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let state = state();
     let mode = server_mode();
-    let proxy_port = config::ports::backend_port(mode);
+    let proxy_port = get_proxy_port();
 
     state.stopping.store(false, Ordering::Relaxed);
-    state.backend_port.store(0, Ordering::Relaxed);
-    state.ready.store(false, Ordering::Relaxed);
+    for worker in &state.workers {
+        worker.handle.backend_port.store(0, Ordering::Relaxed);
+        worker.handle.ready.store(false, Ordering::Relaxed);
+    }
     state.proxy_port.store(proxy_port, Ordering::Relaxed);
 
     if !state.proxy_started.swap(true, Ordering::Relaxed) {
-        let proxy_state = ProxyState::new(state.backend_port.clone(), state.ready.clone());
+        let worker_handles = state.workers.iter().map(|w| w.handle.clone()).collect();
+        let proxy_state = ProxyState::new(worker_handles, app.clone());
         if let Err(err) = start_proxy_server(proxy_port, proxy_state).await {
             state.proxy_started.store(false, Ordering::Relaxed);
             if is_lifetrace_backend(proxy_port).await {
@@ -164,68 +241,79 @@ pub async fn start_backend(
                     "Proxy port {} already has a backend instance, using it directly",
                     proxy_port
                 );
-                state.backend_port.store(proxy_port, Ordering::Relaxed);
-                state.ready.store(true, Ordering::Relaxed);
+                state.workers[0]
+                    .handle
+                    .backend_port
+                    .store(proxy_port, Ordering::Relaxed);
+                state.workers[0].handle.ready.store(true, Ordering::Relaxed);
             } else {
                 return Err(err.into());
             }
         }
     }
 
-    let app_handle = app.clone();
-    tokio::spawn(async move {
-        if let Err(err) = backend_supervisor(app_handle, mode).await {
-            error!("Backend supervisor exited: {}", err);
-        }
-    });
+    for worker_index in 0..state.workers.len() {
+        let app_handle = app.clone();
+        tokio::spawn(async move {
+            if let Err(err) = backend_supervisor(app_handle, mode, worker_index).await {
+                error!("Backend supervisor for worker {} exited: {}", worker_index, err);
+            }
+        });
+    }
 
     Ok(())
 }
 
-async fn backend_supervisor(app: AppHandle, mode: ServerMode) -> Result<(), String> {
+async fn backend_supervisor(
+    app: AppHandle,
+    mode: ServerMode,
+    worker_index: usize,
+) -> Result<(), String> {
     let state = state();
-    let mut backoff = Duration::from_millis(500);
+    let mut backoff = Duration::from_millis(200);
     let max_backoff = Duration::from_secs(10);
-    let interval = Duration::from_millis(config::health_check::BACKEND_INTERVAL);
+    let interval = Duration::from_millis(config::current().backend_interval_ms);
+    let mut consecutive_failures: u32 = 0;
 
     loop {
         if state.stopping.load(Ordering::Relaxed) {
             break;
         }
 
+        let worker = &state.workers[worker_index];
         let mut exited = false;
         let mut managed = false;
         {
-            let mut guard = state.process.lock().unwrap();
+            let mut guard = worker.process.lock().unwrap();
             if let Some(child) = guard.as_mut() {
                 managed = true;
                 match child.try_wait() {
                     Ok(Some(status)) => {
-                        warn!("Backend exited: {}", status);
+                        warn!("Backend worker {} exited: {}", worker_index, status);
                         *guard = None;
                         exited = true;
                     }
                     Ok(None) => {}
                     Err(err) => {
-                        warn!("Failed to check backend status: {}", err);
+                        warn!("Failed to check backend worker {} status: {}", worker_index, err);
                     }
                 }
             }
         }
 
         if exited {
-            state.ready.store(false, Ordering::Relaxed);
-            state.backend_port.store(0, Ordering::Relaxed);
+            worker.handle.ready.store(false, Ordering::Relaxed);
+            worker.handle.backend_port.store(0, Ordering::Relaxed);
         }
 
-        let backend_port = state.backend_port.load(Ordering::Relaxed);
+        let backend_port = worker.handle.backend_port.load(Ordering::Relaxed);
 
         if managed {
             if backend_port != 0 {
                 let healthy = check_backend_health(backend_port).await.unwrap_or(false);
-                state.ready.store(healthy, Ordering::Relaxed);
+                worker.handle.ready.store(healthy, Ordering::Relaxed);
                 if !healthy {
-                    warn!("Backend health check failed");
+                    warn!("Backend worker {} health check failed", worker_index);
                 }
             }
             tokio::time::sleep(interval).await;
@@ -235,33 +323,56 @@ async fn backend_supervisor(app: AppHandle, mode: ServerMode) -> Result<(), Stri
         if backend_port != 0 {
             let healthy = check_backend_health(backend_port).await.unwrap_or(false);
             if healthy {
-                state.ready.store(true, Ordering::Relaxed);
+                worker.handle.ready.store(true, Ordering::Relaxed);
                 tokio::time::sleep(interval).await;
                 continue;
             }
-            state.ready.store(false, Ordering::Relaxed);
-            state.backend_port.store(0, Ordering::Relaxed);
+            worker.handle.ready.store(false, Ordering::Relaxed);
+            worker.handle.backend_port.store(0, Ordering::Relaxed);
         }
 
-        if let Some(port) = detect_running_backend_port(mode).await {
-            state.backend_port.store(port, Ordering::Relaxed);
-            state.ready.store(true, Ordering::Relaxed);
-            backoff = Duration::from_millis(500);
-            tokio::time::sleep(interval).await;
-            continue;
+        // Only worker 0 adopts an externally-detected already-running backend,
+        // so the rest of the pool doesn't race to latch onto the same process.
+        if worker_index == 0 {
+            if let Some(port) = detect_running_backend_port(mode).await {
+                worker.handle.backend_port.store(port, Ordering::Relaxed);
+                worker.handle.ready.store(true, Ordering::Relaxed);
+                backoff = Duration::from_millis(200);
+                consecutive_failures = 0;
+                tokio::time::sleep(interval).await;
+                continue;
+            }
         }
 
-        match start_backend_process(&app, mode).await {
+        match start_backend_process(&app, mode, worker_index).await {
             Ok(port) => {
-                state.backend_port.store(port, Ordering::Relaxed);
-                state.ready.store(true, Ordering::Relaxed);
-                backoff = Duration::from_millis(500);
-                emit_backend_log(&app, format!("Backend ready on port {}", port));
+                worker.handle.backend_port.store(port, Ordering::Relaxed);
+                worker.handle.ready.store(true, Ordering::Relaxed);
+                backoff = Duration::from_millis(200);
+                consecutive_failures = 0;
+                emit_backend_log(
+                    &app,
+                    format!("Backend worker {} ready on port {}", worker_index, port),
+                );
             }
             Err(err) => {
-                state.ready.store(false, Ordering::Relaxed);
-                warn!("Failed to start backend: {}", err);
-                emit_backend_log(&app, format!("Backend start failed: {}", err));
+                worker.handle.ready.store(false, Ordering::Relaxed);
+                consecutive_failures += 1;
+                warn!("Failed to start backend worker {}: {}", worker_index, err);
+                emit_backend_log(
+                    &app,
+                    format!("Backend worker {} start failed: {}", worker_index, err),
+                );
+
+                if consecutive_failures >= MAX_CONSECUTIVE_FAILURES {
+                    let message = format!(
+                        "Backend worker {} failed to start {} times in a row, giving up: {}",
+                        worker_index, consecutive_failures, err
+                    );
+                    emit_labeled_log(&app, "backend", "error", message.clone());
+                    return Err(message);
+                }
+
                 tokio::time::sleep(backoff).await;
                 backoff = (backoff * 2).min(max_backoff);
             }
@@ -273,13 +384,20 @@ async fn backend_supervisor(app: AppHandle, mode: ServerMode) -> Result<(), Stri
     Ok(())
 }
 
-async fn start_backend_process(app: &AppHandle, mode: ServerMode) -> Result<u16, String> {
+async fn start_backend_process(
+    app: &AppHandle,
+    mode: ServerMode,
+    worker_index: usize,
+) -> Result<u16, String> {
     let state = state();
     let backend_runtime = get_backend_runtime();
     let port = pick_backend_port(mode)?;
     let mode_label = mode_label(mode);
 
-    state.ready.store(false, Ordering::Relaxed);
+    state.workers[worker_index]
+        .handle
+        .ready
+        .store(false, Ordering::Relaxed);
 
     let backend_path = if backend_runtime == BackendRuntime::PyInstaller {
         get_backend_path(app).map_err(|e| {
@@ -328,52 +446,106 @@ async fn start_backend_process(app: &AppHandle, mode: ServerMode) -> Result<u16,
         }
         let mut venv_python = None;
 
-        emit_backend_log(app, "Ensuring uv binary is available...");
-        match ensure_uv_binary_with_progress(&runtime_root, |progress| {
-            emit_backend_log(app, format_download_progress(&progress));
-        })
-        .await
-        {
-            Ok(uv_path) => {
-                emit_backend_log(app, format!("uv ready at {}", uv_path.display()));
-                emit_backend_log(app, "Ensuring Python 3.12 via uv...");
-                if let Err(err) = ensure_uv_python(uv_path.as_path()) {
-                    emit_backend_log(app, format!("uv python install failed: {}", err));
-                } else {
-                    emit_backend_log(app, "uv Python install completed.");
-                    emit_backend_log(app, "Creating virtual environment with uv...");
-                    match ensure_uv_venv(uv_path.as_path(), venv_dir.as_path()) {
-                        Ok(path) => {
-                            emit_backend_log(app, "uv venv created.");
-                            emit_backend_log(app, "Installing backend dependencies with uv...");
-                            if let Err(err) = install_requirements(
-                                uv_path.as_path(),
-                                path.as_path(),
-                                requirements_path.as_path(),
-                            ) {
+        // The setup wizard may have already resolved and persisted an
+        // interpreter; use it directly instead of re-running the
+        // uv-then-system-Python fallback cascade on every boot.
+        if let Some(persisted) = config::current().backend_interpreter_path {
+            let persisted_path = Path::new(&persisted).to_path_buf();
+            if persisted_path.exists() {
+                emit_backend_log(
+                    app,
+                    format!("Using persisted interpreter at {}", persisted_path.display()),
+                );
+                let python_path = ensure_venv(persisted_path.as_path(), venv_dir.as_path())?;
+                emit_backend_log(app, "Installing uv in virtual environment...");
+                let uv_path = ensure_uv(python_path.as_path(), venv_dir.as_path())?;
+                emit_backend_log(app, "Installing backend dependencies with uv...");
+                install_requirements(
+                    uv_path.as_path(),
+                    python_path.as_path(),
+                    requirements_path.as_path(),
+                )?;
+                emit_backend_log(app, "uv dependency install completed.");
+                venv_python = Some(python_path);
+            } else {
+                emit_backend_log(
+                    app,
+                    format!(
+                        "Persisted interpreter {} no longer exists, re-detecting...",
+                        persisted_path.display()
+                    ),
+                );
+            }
+        }
+
+        if venv_python.is_none() {
+            emit_backend_log(app, "Ensuring uv binary is available...");
+            match ensure_uv_binary_with_progress(&runtime_root, |progress| {
+                emit_backend_log(app, format_download_progress(&progress));
+            })
+            .await
+            {
+                Ok(uv_path) => {
+                    emit_backend_log(app, format!("uv ready at {}", uv_path.display()));
+                    emit_backend_log(app, "Ensuring Python 3.12 via uv...");
+                    if let Err(err) = ensure_uv_python(uv_path.as_path()) {
+                        emit_backend_log(app, format!("uv python install failed: {}", err));
+                    } else {
+                        emit_backend_log(app, "uv Python install completed.");
+                        emit_backend_log(app, "Creating virtual environment with uv...");
+                        match ensure_uv_venv(uv_path.as_path(), venv_dir.as_path()) {
+                            Ok(path) => {
+                                emit_backend_log(app, "uv venv created.");
                                 emit_backend_log(
                                     app,
-                                    format!("uv dependency install failed: {}", err),
+                                    "Installing backend dependencies with uv...",
+                                );
+                                if let Err(err) = install_requirements(
+                                    uv_path.as_path(),
+                                    path.as_path(),
+                                    requirements_path.as_path(),
+                                ) {
+                                    emit_backend_log(
+                                        app,
+                                        format!("uv dependency install failed: {}", err),
+                                    );
+                                } else {
+                                    emit_backend_log(app, "uv dependency install completed.");
+                                    venv_python = Some(path);
+                                }
+                            }
+                            Err(err) => {
+                                emit_backend_log(
+                                    app,
+                                    format!("uv venv creation failed: {}", err),
                                 );
-                            } else {
-                                emit_backend_log(app, "uv dependency install completed.");
-                                venv_python = Some(path);
                             }
-                        }
-                        Err(err) => {
-                            emit_backend_log(app, format!("uv venv creation failed: {}", err));
                         }
                     }
                 }
-            }
-            Err(err) => {
-                emit_backend_log(app, format!("uv download failed: {}", err));
+                Err(err) => {
+                    emit_backend_log(app, format!("uv download failed: {}", err));
+                }
             }
         }
 
         if venv_python.is_none() {
-            emit_backend_log(app, "Falling back to system Python 3.12...");
-            let system_python = find_python312().ok_or("Python 3.12 not found")?;
+            let system_python = match find_python312() {
+                Some(path) => {
+                    emit_backend_log(app, "Falling back to system Python 3.12...");
+                    path
+                }
+                None => {
+                    emit_backend_log(
+                        app,
+                        "No system Python 3.12 found, downloading a portable build...",
+                    );
+                    ensure_python_distribution_with_progress(&runtime_root, |progress| {
+                        emit_backend_log(app, format_download_progress(&progress));
+                    })
+                    .await?
+                }
+            };
             let fallback_python = ensure_venv(system_python.as_path(), venv_dir.as_path())?;
             emit_backend_log(app, "Installing uv in virtual environment...");
             let uv_path = ensure_uv(fallback_python.as_path(), venv_dir.as_path())?;
@@ -439,8 +611,8 @@ async fn start_backend_process(app: &AppHandle, mode: ServerMode) -> Result<u16,
     }
 
     {
-        let mut guard = state.process.lock().unwrap();
-        *guard = Some(child);
+        let mut guard = state.workers[worker_index].process.lock().unwrap();
+        *guard = Some(KillOnDrop(child));
     }
 
     info!("Waiting for backend server to be ready...");
@@ -452,23 +624,23 @@ async fn start_backend_process(app: &AppHandle, mode: ServerMode) -> Result<u16,
     )
     .await
     {
-        stop_managed_backend();
+        stop_managed_worker(worker_index);
         emit_backend_log(app, format!("Backend failed to become ready: {}", err));
         return Err(err);
     }
     info!("Backend server is ready at http://127.0.0.1:{}", port);
 
     if let Err(err) = verify_backend_mode(port, mode_label).await {
-        stop_managed_backend();
+        stop_managed_worker(worker_index);
         return Err(err);
     }
 
     Ok(port)
 }
 
-fn stop_managed_backend() {
+pub(crate) fn stop_managed_worker(worker_index: usize) {
     let state = state();
-    let mut guard = state.process.lock().unwrap();
+    let mut guard = state.workers[worker_index].process.lock().unwrap();
     if let Some(child) = guard.take() {
         #[cfg(unix)]
         {
@@ -485,44 +657,60 @@ fn stop_managed_backend() {
     }
 }
 
-/// Stop the backend server
+/// Restart every pooled worker in place (e.g. after a dev-mode source
+/// change). Each worker is marked not ready and stopped; its supervisor loop
+/// detects the exit on its next tick and re-spawns it via
+/// `start_backend_process`, so this doesn't need to wait for the restart
+/// itself.
+pub fn restart_all_workers() {
+    let state = state();
+    for (worker_index, worker) in state.workers.iter().enumerate() {
+        worker.handle.ready.store(false, Ordering::Relaxed);
+        stop_managed_worker(worker_index);
+    }
+}
+
+/// Stop every pooled backend worker.
 pub fn stop_backend() {
     let state = state();
     state.stopping.store(true, Ordering::Relaxed);
-    state.ready.store(false, Ordering::Relaxed);
 
-    let mut guard = state.process.lock().unwrap();
-    if let Some(mut child) = guard.take() {
-        info!("Stopping backend server...");
+    for worker in &state.workers {
+        worker.handle.ready.store(false, Ordering::Relaxed);
 
-        // Try graceful shutdown first
-        #[cfg(unix)]
-        {
-            unsafe {
-                libc::kill(child.id() as i32, libc::SIGTERM);
-            }
-        }
+        let mut guard = worker.process.lock().unwrap();
+        if let Some(mut child) = guard.take() {
+            info!("Stopping backend worker...");
 
-        #[cfg(windows)]
-        {
-            let _ = child.kill();
-        }
-
-        // Wait a bit for graceful shutdown
-        std::thread::sleep(Duration::from_secs(2));
-
-        // Force kill if still running
-        match child.try_wait() {
-            Ok(Some(_)) => {
-                info!("Backend server stopped gracefully");
+            // Try graceful shutdown first
+            #[cfg(unix)]
+            {
+                unsafe {
+                    libc::kill(child.id() as i32, libc::SIGTERM);
+                }
             }
-            Ok(None) => {
-                warn!("Backend server did not stop gracefully, forcing kill");
+
+            #[cfg(windows)]
+            {
                 let _ = child.kill();
             }
-            Err(e) => {
-                error!("Error checking backend status: {}", e);
-                let _ = child.kill();
+
+            // Wait a bit for graceful shutdown
+            std::thread::sleep(Duration::from_secs(2));
+
+            // Force kill if still running
+            match child.try_wait() {
+                Ok(Some(_)) => {
+                    info!("Backend worker stopped gracefully");
+                }
+                Ok(None) => {
+                    warn!("Backend worker did not stop gracefully, forcing kill");
+                    let _ = child.kill();
+                }
+                Err(e) => {
+                    error!("Error checking backend worker status: {}", e);
+                    let _ = child.kill();
+                }
             }
         }
     }
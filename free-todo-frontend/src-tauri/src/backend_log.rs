@@ -6,9 +6,16 @@ use std::io::{BufRead, BufReader};
 use tauri::{AppHandle, Emitter};
 
 pub fn emit_backend_log(app: &AppHandle, message: impl Into<String>) {
+    emit_labeled_log(app, "backend", "info", message);
+}
+
+/// Like [`emit_backend_log`], but also tags the line with a label/level pair
+/// for the `log_gateway` subscribers (the Tauri event text is unchanged).
+pub fn emit_labeled_log(app: &AppHandle, label: &str, level: &str, message: impl Into<String>) {
     let message = message.into();
     info!("backend-log: {}", message);
-    let _ = app.emit("backend-log", message);
+    let _ = app.emit("backend-log", message.clone());
+    crate::log_gateway::publish(label, level, &message);
 }
 
 pub fn spawn_log_reader(
@@ -19,7 +26,7 @@ pub fn spawn_log_reader(
     std::thread::spawn(move || {
         let reader = BufReader::new(stream);
         for line in reader.lines().map_while(Result::ok) {
-            emit_backend_log(&app, format!("[{}] {}", label, line));
+            emit_labeled_log(&app, label, "info", format!("[{}] {}", label, line));
         }
     });
 }
@@ -1,29 +1,36 @@
 //! Backend path resolution helpers
-
-use crate::config::{process, ServerMode};
+//!
+//! The executable name, extra search dirs, data-dir naming, and requirements
+//! filename below all come from the layered [`crate::config::Config`]
+//! (`[backend_paths]` in `lifetrace.toml`, see its doc comments for the
+//! first-launch caveat on the data-dir fields) instead of their own separate
+//! file - the candidate directories and file names themselves remain
+//! baked-in defaults that those config fields override.
+
+use crate::config::{self, ServerMode};
 use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
 
 /// Get backend executable path for PyInstaller runtime
 pub fn get_backend_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let cfg = config::current();
+    let exec_name = cfg.backend_exec_name.as_str();
+
     let resource_path = app
         .path()
         .resource_dir()
         .map_err(|e| format!("Failed to get resource dir: {}", e))?;
 
-    let packaged_backend = resource_path
-        .join("backend")
-        .join(process::BACKEND_EXEC_NAME);
-    if packaged_backend.exists() {
-        return Ok(packaged_backend);
-    }
+    let mut candidates: Vec<PathBuf> = cfg
+        .backend_extra_search_dirs
+        .iter()
+        .map(|dir| Path::new(dir).join(exec_name))
+        .collect();
 
-    let packaged_dist = resource_path
-        .join("dist-backend")
-        .join(process::BACKEND_EXEC_NAME);
-    if packaged_dist.exists() {
-        return Ok(packaged_dist);
-    }
+    let packaged_backend = resource_path.join("backend").join(exec_name);
+    let packaged_dist = resource_path.join("dist-backend").join(exec_name);
+    candidates.push(packaged_backend.clone());
+    candidates.push(packaged_dist.clone());
 
     // Development mode: try dist-backend
     let dev_path = std::env::current_dir()
@@ -31,29 +38,36 @@ pub fn get_backend_path(app: &AppHandle) -> Result<PathBuf, String> {
         .parent()
         .ok_or("Failed to get parent dir")?
         .join("dist-backend")
-        .join(process::BACKEND_EXEC_NAME);
-
-    if dev_path.exists() {
-        Ok(dev_path)
-    } else {
-        Err(format!(
-            "Backend executable not found at {:?} or {:?} or {:?}",
-            packaged_backend, packaged_dist, dev_path
-        ))
-    }
+        .join(exec_name);
+    candidates.push(dev_path.clone());
+
+    candidates
+        .into_iter()
+        .find(|candidate| candidate.exists())
+        .ok_or_else(|| {
+            format!(
+                "Backend executable not found at {:?} or {:?} or {:?}",
+                packaged_backend, packaged_dist, dev_path
+            )
+        })
 }
 
 /// Locate backend script root (for script runtime)
 pub fn get_backend_script_root(app: &AppHandle) -> Result<PathBuf, String> {
+    let cfg = config::current();
+
     let resource_path = app
         .path()
         .resource_dir()
         .map_err(|e| format!("Failed to get resource dir: {}", e))?;
 
-    let candidates = [
-        resource_path.join("backend"),
-        resource_path.join("lifetrace"),
-    ];
+    let mut candidates: Vec<PathBuf> = cfg
+        .backend_extra_search_dirs
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+    candidates.push(resource_path.join("backend"));
+    candidates.push(resource_path.join("lifetrace"));
 
     for candidate in candidates {
         let script_path = candidate
@@ -99,17 +113,19 @@ pub fn get_backend_script_entry(root: &Path) -> PathBuf {
 }
 
 pub fn get_requirements_path(root: &Path) -> PathBuf {
-    let nested = root.join("requirements-runtime.txt");
+    let filename = config::current().backend_requirements_filename;
+
+    let nested = root.join(&filename);
     if nested.exists() {
         return nested;
     }
     if let Some(parent) = root.parent() {
-        let parent_req = parent.join("requirements-runtime.txt");
+        let parent_req = parent.join(&filename);
         if parent_req.exists() {
             return parent_req;
         }
     }
-    let fallback = root.join("backend").join("requirements-runtime.txt");
+    let fallback = root.join("backend").join(&filename);
     if fallback.exists() {
         return fallback;
     }
@@ -131,17 +147,20 @@ pub fn get_runtime_root(app: &AppHandle) -> Result<PathBuf, String> {
 
 /// Get data directory for backend
 pub fn get_data_dir(app: &AppHandle, mode: ServerMode) -> Result<PathBuf, String> {
+    let cfg = config::current();
+
     let app_data_dir = app
         .path()
         .app_data_dir()
         .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
-    let legacy_dir = app_data_dir.join(process::BACKEND_DATA_DIR);
+    let data_dir_name = cfg.backend_data_dir_name.as_str();
     let mode_suffix = match mode {
-        ServerMode::Dev => "dev",
-        ServerMode::Build => "build",
+        ServerMode::Dev => cfg.backend_dev_suffix.as_str(),
+        ServerMode::Build => cfg.backend_build_suffix.as_str(),
     };
-    let mode_dir = app_data_dir.join(format!("{}-{}", process::BACKEND_DATA_DIR, mode_suffix));
+    let legacy_dir = app_data_dir.join(data_dir_name);
+    let mode_dir = app_data_dir.join(format!("{}-{}", data_dir_name, mode_suffix));
 
     let data_dir = if mode == ServerMode::Build && legacy_dir.exists() {
         legacy_dir
@@ -156,3 +175,8 @@ pub fn get_data_dir(app: &AppHandle, mode: ServerMode) -> Result<PathBuf, String
 
     Ok(data_dir)
 }
+
+/// Resolve the proxy's listen port from the layered [`crate::config::Config`].
+pub fn get_proxy_port() -> u16 {
+    config::current().backend_port
+}
@@ -5,35 +5,89 @@ use axum::{
     extract::State,
     http::{header, Request, StatusCode},
     response::Response,
-    Router,
+    routing::{get, post},
+    Json, Router,
 };
-use log::warn;
+use hyper_util::rt::TokioIo;
+use log::{info, warn};
 use reqwest::Client;
+use serde::Deserialize;
 use serde_json::json;
 use std::sync::{
-    atomic::{AtomicBool, AtomicU16, Ordering},
+    atomic::{AtomicBool, AtomicU16, AtomicUsize, AtomicU64, Ordering},
     Arc,
 };
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tracing::Instrument;
+
+use crate::actions::{self, ShortcutAction};
+use crate::config;
+
+/// A single pooled backend worker's port and health, shared between the
+/// supervisor that owns the worker's process and the proxy that routes to it.
+#[derive(Clone, Default)]
+pub struct WorkerHandle {
+    pub backend_port: Arc<AtomicU16>,
+    pub ready: Arc<AtomicBool>,
+}
+
+impl WorkerHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Aggregate proxy counters exposed via `/__proxy/metrics`, for diagnosing
+/// whether a hang is in the proxy, the transport, or the Python backend.
+#[derive(Default)]
+struct ProxyMetrics {
+    total_requests: AtomicU64,
+    upstream_errors: AtomicU64,
+    unready_503s: AtomicU64,
+}
 
 #[derive(Clone)]
 pub struct ProxyState {
-    backend_port: Arc<AtomicU16>,
-    ready: Arc<AtomicBool>,
+    workers: Arc<Vec<WorkerHandle>>,
+    next: Arc<AtomicUsize>,
     client: Client,
+    app: AppHandle,
+    metrics: Arc<ProxyMetrics>,
 }
 
 impl ProxyState {
-    pub fn new(backend_port: Arc<AtomicU16>, ready: Arc<AtomicBool>) -> Self {
+    pub fn new(workers: Vec<WorkerHandle>, app: AppHandle) -> Self {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .build()
             .unwrap_or_default();
         Self {
-            backend_port,
-            ready,
+            workers: Arc::new(workers),
+            next: Arc::new(AtomicUsize::new(0)),
             client,
+            app,
+            metrics: Arc::new(ProxyMetrics::default()),
+        }
+    }
+
+    /// Round-robin across the pool, skipping any worker that is not
+    /// currently healthy. Returns `None` if every worker is down.
+    fn pick_worker(&self) -> Option<u16> {
+        let len = self.workers.len();
+        if len == 0 {
+            return None;
         }
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        (0..len).find_map(|offset| {
+            let worker = &self.workers[(start + offset) % len];
+            let port = worker.backend_port.load(Ordering::Relaxed);
+            (port != 0 && worker.ready.load(Ordering::Relaxed)).then_some(port)
+        })
+    }
+
+    fn any_ready(&self) -> bool {
+        self.workers.iter().any(|w| w.ready.load(Ordering::Relaxed))
     }
 }
 
@@ -42,7 +96,11 @@ pub async fn start_proxy_server(port: u16, state: ProxyState) -> Result<(), Stri
         .await
         .map_err(|e| format!("Failed to bind proxy port {}: {}", port, e))?;
 
-    let app = Router::new().fallback(proxy_handler).with_state(state);
+    let app = Router::new()
+        .route("/__app/shortcut", post(shortcut_handler))
+        .route("/__proxy/metrics", get(metrics_handler))
+        .fallback(proxy_handler)
+        .with_state(state);
 
     tokio::spawn(async move {
         if let Err(err) = axum::serve(listener, app).await {
@@ -53,18 +111,110 @@ pub async fn start_proxy_server(port: u16, state: ProxyState) -> Result<(), Stri
     Ok(())
 }
 
+#[derive(Deserialize)]
+struct ShortcutRequest {
+    action: String,
+}
+
+/// Local control endpoint so the `freetodo-cli` tool (or any other script)
+/// can trigger the same actions as a global shortcut or tray click without
+/// launching a second GUI instance.
+async fn shortcut_handler(
+    State(state): State<ProxyState>,
+    Json(request): Json<ShortcutRequest>,
+) -> Response<Body> {
+    match ShortcutAction::parse(&request.action) {
+        Some(action) => {
+            actions::dispatch(&state.app, action);
+            let mut response = Response::new(Body::from(json!({"status": "ok"}).to_string()));
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static("application/json"),
+            );
+            response
+        }
+        None => {
+            let mut response = Response::new(Body::from(
+                json!({"status": "error", "message": "unknown action"}).to_string(),
+            ));
+            *response.status_mut() = StatusCode::BAD_REQUEST;
+            response.headers_mut().insert(
+                header::CONTENT_TYPE,
+                header::HeaderValue::from_static("application/json"),
+            );
+            response
+        }
+    }
+}
+
+/// Opt-in diagnostics endpoint reporting aggregate proxy counters as JSON.
+async fn metrics_handler(State(state): State<ProxyState>) -> Response<Body> {
+    let backend_port = state.pick_worker().unwrap_or(0);
+    let payload = json!({
+        "total_requests": state.metrics.total_requests.load(Ordering::Relaxed),
+        "upstream_errors": state.metrics.upstream_errors.load(Ordering::Relaxed),
+        "unready_503s": state.metrics.unready_503s.load(Ordering::Relaxed),
+        "ready": state.any_ready(),
+        "backend_port": backend_port,
+    });
+    let mut response = Response::new(Body::from(payload.to_string()));
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
 async fn proxy_handler(State(state): State<ProxyState>, req: Request<Body>) -> Response<Body> {
+    let method = req.method().clone();
+    let path_owned = req.uri().path().to_string();
+    let span = tracing::info_span!(
+        "proxy_request",
+        %method,
+        path = %path_owned,
+        status = tracing::field::Empty,
+        response_bytes = tracing::field::Empty,
+        duration_ms = tracing::field::Empty,
+    );
+
+    let start = Instant::now();
+    state.metrics.total_requests.fetch_add(1, Ordering::Relaxed);
+    let response = proxy_dispatch(state, req).instrument(span.clone()).await;
+
+    span.record("status", response.status().as_u16());
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    response
+}
+
+async fn proxy_dispatch(state: ProxyState, req: Request<Body>) -> Response<Body> {
     let path = req.uri().path();
     if path == "/ready" {
-        let backend_port = state.backend_port.load(Ordering::Relaxed);
-        let ready = state.ready.load(Ordering::Relaxed);
-        return ready_response(ready, backend_port);
+        let ready = state.any_ready();
+        if !ready {
+            state.metrics.unready_503s.fetch_add(1, Ordering::Relaxed);
+        }
+        return ready_response(ready, state.pick_worker().unwrap_or(0));
+    }
+
+    let config = config::current();
+    if !config.api_prefixes.iter().any(|prefix| path.starts_with(prefix.as_str())) {
+        if let Some(root) = &config.asset_root {
+            if let Some(response) = try_serve_static(root, path, req.headers().get(header::IF_MODIFIED_SINCE)) {
+                return response;
+            }
+        }
     }
 
-    let backend_port = state.backend_port.load(Ordering::Relaxed);
-    let ready = state.ready.load(Ordering::Relaxed);
-    if backend_port == 0 || !ready {
-        return ready_response(false, backend_port);
+    let backend_port = match state.pick_worker() {
+        Some(port) => port,
+        None => {
+            state.metrics.unready_503s.fetch_add(1, Ordering::Relaxed);
+            return ready_response(false, 0);
+        }
+    };
+
+    if is_upgrade_request(&req) {
+        return proxy_upgrade(req, backend_port).await;
     }
 
     let path_and_query = req
@@ -82,27 +232,41 @@ async fn proxy_handler(State(state): State<ProxyState>, req: Request<Body>) -> R
         }
         builder = builder.header(name, value);
     }
+    // Stream the request body straight through rather than buffering it, so
+    // large uploads (e.g. screen recordings) don't have to fit in memory.
+    builder = builder.body(reqwest::Body::wrap_stream(body.into_data_stream()));
 
-    let body_bytes = match to_bytes(body, usize::MAX).await {
-        Ok(bytes) => bytes,
-        Err(err) => {
-            warn!("Proxy body read failed: {}", err);
-            return ready_response(false, backend_port);
-        }
-    };
-
-    match builder.body(body_bytes).send().await {
+    match builder.send().await {
         Ok(response) => {
             let status = response.status();
             let headers = response.headers().clone();
-            let bytes = match response.bytes().await {
-                Ok(body) => body,
-                Err(err) => {
-                    warn!("Proxy response read failed: {}", err);
-                    return ready_response(false, backend_port);
+            let content_length = response.content_length();
+            // Only bodies small enough to be worth buffering get one; a
+            // real Content-Length also lets us pass it straight through.
+            // Everything else (chunked responses, SSE token streams with no
+            // known length) is streamed so the response starts flowing
+            // before the upstream is done.
+            let buffer_whole = content_length
+                .map(|len| len <= SMALL_BODY_THRESHOLD)
+                .unwrap_or(false);
+
+            let body = if buffer_whole {
+                match response.bytes().await {
+                    Ok(bytes) => Body::from(bytes),
+                    Err(err) => {
+                        state.metrics.upstream_errors.fetch_add(1, Ordering::Relaxed);
+                        tracing::warn!("Proxy response read failed: {}", err);
+                        return ready_response(false, backend_port);
+                    }
                 }
+            } else {
+                Body::from_stream(response.bytes_stream())
             };
 
+            if let Some(len) = content_length {
+                tracing::Span::current().record("response_bytes", len);
+            }
+
             let mut builder = Response::builder().status(status);
             for (name, value) in headers.iter() {
                 if should_skip_response_header(name) {
@@ -110,18 +274,87 @@ async fn proxy_handler(State(state): State<ProxyState>, req: Request<Body>) -> R
                 }
                 builder = builder.header(name, value);
             }
-            builder = builder.header(header::CONTENT_LENGTH, bytes.len().to_string());
+            if let Some(len) = content_length.filter(|_| buffer_whole) {
+                builder = builder.header(header::CONTENT_LENGTH, len.to_string());
+            }
             builder
-                .body(Body::from(bytes))
+                .body(body)
                 .unwrap_or_else(|_| ready_response(false, backend_port))
         }
         Err(err) => {
-            warn!("Proxy request failed: {}", err);
+            state.metrics.upstream_errors.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!("Proxy request failed: {}", err);
             ready_response(false, backend_port)
         }
     }
 }
 
+/// Responses at or under this size are buffered and given an explicit
+/// `Content-Length`; anything larger (or with no known length at all, like
+/// a chunked SSE stream) is streamed straight through instead.
+const SMALL_BODY_THRESHOLD: u64 = 1024 * 1024;
+
+/// Try to serve `path` as a static file under `root`, short-circuiting the
+/// backend hop entirely. Returns `None` (falling through to the backend) if
+/// the path escapes `root`, doesn't exist, or isn't a regular file.
+fn try_serve_static(
+    root: &std::path::Path,
+    path: &str,
+    if_modified_since: Option<&header::HeaderValue>,
+) -> Option<Response<Body>> {
+    let file_path = resolve_asset_path(root, path)?;
+    let metadata = std::fs::metadata(&file_path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+
+    let modified = metadata.modified().ok()?;
+    if let Some(since) = if_modified_since
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| httpdate::parse_http_date(value).ok())
+    {
+        // HTTP dates are second-resolution, so truncate our side to match
+        // before comparing, or every request would miss the cache.
+        let modified_secs = modified
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let since_secs = since
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(u64::MAX);
+        if modified_secs <= since_secs {
+            return Some(
+                Response::builder()
+                    .status(StatusCode::NOT_MODIFIED)
+                    .body(Body::empty())
+                    .unwrap_or_else(|_| ready_response(false, 0)),
+            );
+        }
+    }
+
+    let bytes = std::fs::read(&file_path).ok()?;
+    let content_type = mime_guess::from_path(&file_path).first_or_octet_stream();
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type.as_ref())
+        .header(header::LAST_MODIFIED, httpdate::fmt_http_date(modified))
+        .body(Body::from(bytes))
+        .ok()
+}
+
+/// Join `path` onto `root` and canonicalize the result, rejecting anything
+/// that escapes `root` (`..` traversal, symlinks pointing outside it, ...).
+fn resolve_asset_path(root: &std::path::Path, path: &str) -> Option<std::path::PathBuf> {
+    let relative = path.trim_start_matches('/');
+    let relative = if relative.is_empty() { "index.html" } else { relative };
+
+    let root = root.canonicalize().ok()?;
+    let candidate = root.join(relative).canonicalize().ok()?;
+    candidate.starts_with(&root).then_some(candidate)
+}
+
 fn ready_response(ready: bool, backend_port: u16) -> Response<Body> {
     let payload = if ready {
         json!({
@@ -147,6 +380,274 @@ fn ready_response(ready: bool, backend_port: u16) -> Response<Body> {
     response
 }
 
+/// Whether the client is asking to upgrade the connection (WebSocket, or any
+/// other `Connection: upgrade` protocol the backend speaks). These requests
+/// can't go through the plain `reqwest` path below - they need a raw
+/// tunnel, see `proxy_upgrade`.
+fn is_upgrade_request(req: &Request<Body>) -> bool {
+    let has_upgrade_header = req.headers().get(header::UPGRADE).is_some();
+    let connection_says_upgrade = req
+        .headers()
+        .get(header::CONNECTION)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            value
+                .split(',')
+                .any(|token| token.trim().eq_ignore_ascii_case("upgrade"))
+        })
+        .unwrap_or(false);
+    has_upgrade_header && connection_says_upgrade
+}
+
+/// Proxy an upgrade request (WebSocket, SSE-over-upgrade, ...) by dialing
+/// the backend directly, relaying its `101 Switching Protocols` response
+/// back to the client, and then splicing the two upgraded byte streams
+/// together until either side closes or the idle timeout elapses.
+async fn proxy_upgrade(req: Request<Body>, backend_port: u16) -> Response<Body> {
+    let path_and_query = req
+        .uri()
+        .path_and_query()
+        .map(|value| value.as_str())
+        .unwrap_or("/")
+        .to_string();
+
+    let stream = match tokio::net::TcpStream::connect(("127.0.0.1", backend_port)).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            warn!("Upgrade tunnel: failed to connect to backend: {}", err);
+            return ready_response(false, backend_port);
+        }
+    };
+
+    let (mut sender, connection) = match hyper::client::conn::http1::handshake(TokioIo::new(stream)).await {
+        Ok(pair) => pair,
+        Err(err) => {
+            warn!("Upgrade tunnel: handshake with backend failed: {}", err);
+            return ready_response(false, backend_port);
+        }
+    };
+    tokio::spawn(async move {
+        if let Err(err) = connection.with_upgrades().await {
+            warn!("Upgrade tunnel: backend connection error: {}", err);
+        }
+    });
+
+    let (parts, body) = req.into_parts();
+    let mut upstream_req_builder = Request::builder()
+        .method(parts.method.clone())
+        .uri(&path_and_query);
+    for (name, value) in parts.headers.iter() {
+        if should_skip_upgrade_header(name) {
+            continue;
+        }
+        upstream_req_builder = upstream_req_builder.header(name, value);
+    }
+    let upstream_req = match upstream_req_builder.body(Body::empty()) {
+        Ok(request) => request,
+        Err(err) => {
+            warn!("Upgrade tunnel: failed to build upstream request: {}", err);
+            return ready_response(false, backend_port);
+        }
+    };
+
+    // Rebuild the inbound request so we can take its upgrade later, once we
+    // know the backend actually agreed to switch protocols.
+    let mut inbound_req = Request::from_parts(parts, body);
+
+    let upstream_resp = match sender.send_request(upstream_req).await {
+        Ok(resp) => resp,
+        Err(err) => {
+            warn!("Upgrade tunnel: request to backend failed: {}", err);
+            return ready_response(false, backend_port);
+        }
+    };
+
+    if upstream_resp.status() != StatusCode::SWITCHING_PROTOCOLS {
+        // Backend declined the upgrade; relay its response as a normal one.
+        let status = upstream_resp.status();
+        let headers = upstream_resp.headers().clone();
+        let bytes = match to_bytes(upstream_resp.into_body(), usize::MAX).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                warn!("Upgrade tunnel: failed to read declined-upgrade response: {}", err);
+                return ready_response(false, backend_port);
+            }
+        };
+        let mut builder = Response::builder().status(status);
+        for (name, value) in headers.iter() {
+            if should_skip_response_header(name) {
+                continue;
+            }
+            builder = builder.header(name, value);
+        }
+        return builder
+            .body(Body::from(bytes))
+            .unwrap_or_else(|_| ready_response(false, backend_port));
+    }
+
+    let mut response_builder = Response::builder().status(StatusCode::SWITCHING_PROTOCOLS);
+    for (name, value) in upstream_resp.headers().iter() {
+        if should_skip_upgrade_header(name) {
+            continue;
+        }
+        response_builder = response_builder.header(name, value);
+    }
+    let response = match response_builder.body(Body::empty()) {
+        Ok(response) => response,
+        Err(err) => {
+            warn!("Upgrade tunnel: failed to build 101 response: {}", err);
+            return ready_response(false, backend_port);
+        }
+    };
+
+    let idle_timeout = Duration::from_millis(config::current().upgrade_idle_ms);
+    tokio::spawn(async move {
+        let upstream_upgraded = match hyper::upgrade::on(upstream_resp).await {
+            Ok(upgraded) => upgraded,
+            Err(err) => {
+                warn!("Upgrade tunnel: backend upgrade failed: {}", err);
+                return;
+            }
+        };
+        let client_upgraded = match hyper::upgrade::on(&mut inbound_req).await {
+            Ok(upgraded) => upgraded,
+            Err(err) => {
+                warn!("Upgrade tunnel: client upgrade failed: {}", err);
+                return;
+            }
+        };
+
+        // Shared "last activity" clock (ms since `started`), touched by
+        // either side on every successful read/write, so the watchdog below
+        // can tell real inactivity apart from one side just being slow to
+        // finish copying a large, still-active transfer.
+        let started = Instant::now();
+        let last_activity = Arc::new(AtomicU64::new(0));
+        let mut upstream_io = ActivityTracked::new(
+            TokioIo::new(upstream_upgraded),
+            last_activity.clone(),
+            started,
+        );
+        let mut client_io =
+            ActivityTracked::new(TokioIo::new(client_upgraded), last_activity.clone(), started);
+
+        let copy = tokio::spawn(async move {
+            tokio::io::copy_bidirectional(&mut client_io, &mut upstream_io).await
+        });
+        tokio::pin!(copy);
+
+        let mut ticker = tokio::time::interval(Duration::from_secs(1));
+        ticker.tick().await; // first tick fires immediately
+
+        loop {
+            tokio::select! {
+                result = &mut copy => {
+                    match result {
+                        Ok(Ok((to_upstream, to_client))) => {
+                            info!(
+                                "Upgrade tunnel closed ({} bytes to backend, {} bytes to client)",
+                                to_upstream, to_client
+                            );
+                        }
+                        Ok(Err(err)) => warn!("Upgrade tunnel I/O error: {}", err),
+                        Err(err) => warn!("Upgrade tunnel task failed: {}", err),
+                    }
+                    break;
+                }
+                _ = ticker.tick() => {
+                    let idle_for = started.elapsed().as_millis() as u64
+                        - last_activity.load(Ordering::Relaxed);
+                    if idle_for >= idle_timeout.as_millis() as u64 {
+                        warn!("Upgrade tunnel idle timeout reached, closing");
+                        copy.abort();
+                        break;
+                    }
+                }
+            }
+        }
+    });
+
+    response
+}
+
+/// Wraps an upgraded I/O half and records the time of its last successful
+/// read/write into a shared atomic (milliseconds since a common `Instant`),
+/// so `proxy_upgrade`'s watchdog can close the tunnel after real inactivity
+/// instead of capping the whole connection's total lifetime.
+struct ActivityTracked<T> {
+    inner: T,
+    last_activity: Arc<AtomicU64>,
+    started: Instant,
+}
+
+impl<T> ActivityTracked<T> {
+    fn new(inner: T, last_activity: Arc<AtomicU64>, started: Instant) -> Self {
+        Self {
+            inner,
+            last_activity,
+            started,
+        }
+    }
+
+    fn touch(&self) {
+        self.last_activity
+            .store(self.started.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
+impl<T: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for ActivityTracked<T> {
+    fn poll_read(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        let before = buf.filled().len();
+        let poll = std::pin::Pin::new(&mut self.inner).poll_read(cx, buf);
+        if poll.is_ready() && buf.filled().len() > before {
+            self.touch();
+        }
+        poll
+    }
+}
+
+impl<T: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for ActivityTracked<T> {
+    fn poll_write(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        data: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        let poll = std::pin::Pin::new(&mut self.inner).poll_write(cx, data);
+        if let std::task::Poll::Ready(Ok(n)) = &poll {
+            if *n > 0 {
+                self.touch();
+            }
+        }
+        poll
+    }
+
+    fn poll_flush(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+/// Headers that must survive onto the upgrade request/response untouched -
+/// `Connection`/`Upgrade`/`Sec-WebSocket-*` are exactly what negotiates the
+/// protocol switch, unlike the plain `reqwest` path where `Connection` is
+/// stripped.
+fn should_skip_upgrade_header(name: &header::HeaderName) -> bool {
+    *name == header::HOST || *name == header::CONTENT_LENGTH
+}
+
 fn should_skip_request_header(name: &header::HeaderName) -> bool {
     *name == header::HOST || *name == header::CONTENT_LENGTH || *name == header::CONNECTION
 }
@@ -13,6 +13,7 @@ use std::time::Duration;
 struct HealthResponse {
     app: Option<String>,
     server_mode: Option<String>,
+    recording: Option<bool>,
 }
 
 fn backend_port_range(mode: ServerMode) -> (u16, u16) {
@@ -63,6 +64,22 @@ pub async fn check_backend_health(
     }
 }
 
+/// Query `/health`'s `recording` field, used by `recording::start_polling`
+/// to keep the tray's recording menu items in sync with the backend.
+pub async fn check_recording_state(port: u16) -> Option<bool> {
+    let url = format!("http://127.0.0.1:{}/health", port);
+    let client = Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .ok()?;
+
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json::<HealthResponse>().await.ok()?.recording
+}
+
 pub async fn detect_running_backend_port(mode: ServerMode) -> Option<u16> {
     let (start_port, end_port) = backend_port_range(mode);
 
@@ -103,6 +120,11 @@ pub fn pick_backend_port(mode: ServerMode) -> Result<u16, String> {
     ))
 }
 
+/// Polls until the backend answers healthy or `timeout_secs` elapses. Wrapped
+/// in its own span (rather than `tracing::info!`-per-retry) so a stalled
+/// backend startup shows up as one long-running span instead of a wall of
+/// identical retry log lines.
+#[tracing::instrument(skip(health_timeout_ms, retry_ms))]
 pub async fn wait_for_backend(
     port: u16,
     timeout_secs: u64,
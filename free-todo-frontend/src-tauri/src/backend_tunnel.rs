@@ -0,0 +1,214 @@
+//! Secure remote tunnel to the local Python backend.
+//!
+//! Ports the "code tunnel" idea from the VS Code CLI: on demand, opens an
+//! outbound persistent connection to a relay endpoint, registers a tunnel
+//! name, and multiplexes incoming remote HTTP requests back into the
+//! backend proxy port exposed by `backend::get_backend_url`. Every
+//! forwarded request must carry the bearer token generated for this device,
+//! and requests are rejected with 503 until the backend reports ready.
+
+use crate::backend_log::emit_backend_log;
+use crate::relay;
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+
+fn relay_url() -> String {
+    std::env::var("FREETODO_BACKEND_RELAY_URL")
+        .unwrap_or_else(|_| "wss://relay.freetodo.app/backend-tunnel".to_string())
+}
+
+static TUNNEL_RUNNING: AtomicBool = AtomicBool::new(false);
+static SHUTDOWN: Mutex<Option<oneshot::Sender<()>>> = Mutex::new(None);
+static PUBLIC_URL: Mutex<Option<String>> = Mutex::new(None);
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RelayMessage {
+    Registered {
+        public_url: String,
+    },
+    ForwardRequest {
+        id: String,
+        method: String,
+        path: String,
+        #[serde(default)]
+        body: String,
+        #[serde(default)]
+        token: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RelayReply {
+    ForwardResponse { id: String, status: u16, body: String },
+}
+
+fn token_file(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| e.to_string())?;
+    Ok(data_dir.join("backend-tunnel-token"))
+}
+
+/// Load the persisted per-device bearer token, generating and saving one on
+/// first use.
+pub fn get_or_create_token(app: &AppHandle) -> Result<String, String> {
+    let path = token_file(app)?;
+    if let Ok(existing) = std::fs::read_to_string(&path) {
+        let trimmed = existing.trim().to_string();
+        if !trimmed.is_empty() {
+            return Ok(trimmed);
+        }
+    }
+
+    let token = relay::generate_token();
+    std::fs::write(&path, &token).map_err(|e| format!("Failed to persist token: {}", e))?;
+    Ok(token)
+}
+
+/// Start the tunnel, returning the shareable public URL once the relay has
+/// registered it. No-op (returns the cached URL) if already running.
+#[tauri::command]
+pub async fn start_backend_tunnel(app: AppHandle) -> Result<String, String> {
+    if TUNNEL_RUNNING.swap(true, Ordering::SeqCst) {
+        if let Some(url) = PUBLIC_URL.lock().unwrap().clone() {
+            return Ok(url);
+        }
+    }
+
+    let token = get_or_create_token(&app)?;
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    *SHUTDOWN.lock().unwrap() = Some(shutdown_tx);
+
+    let (mut write, mut read) = relay::connect_and_register(&relay_url(), &token).await?;
+
+    // Wait for the relay's acknowledgement so the command can hand the
+    // shareable URL straight back to the caller.
+    let public_url = loop {
+        match read.next().await {
+            Some(Ok(Message::Text(text))) => {
+                if let Ok(RelayMessage::Registered { public_url }) = serde_json::from_str(&text) {
+                    break public_url;
+                }
+            }
+            Some(Ok(_)) => continue,
+            _ => return Err("Relay closed the connection before registering".to_string()),
+        }
+    };
+    *PUBLIC_URL.lock().unwrap() = Some(public_url.clone());
+    emit_backend_log(&app, format!("Backend tunnel ready at {}", public_url));
+
+    let app_for_task = app.clone();
+    let token_for_task = token.clone();
+    tokio::spawn(async move {
+        if let Err(err) =
+            serve_forwarded_requests(&token_for_task, write, read, shutdown_rx).await
+        {
+            warn!("Backend tunnel session ended: {}", err);
+            emit_backend_log(&app_for_task, format!("Backend tunnel disconnected: {}", err));
+        }
+        TUNNEL_RUNNING.store(false, Ordering::SeqCst);
+        *PUBLIC_URL.lock().unwrap() = None;
+    });
+
+    Ok(public_url)
+}
+
+async fn serve_forwarded_requests(
+    token: &str,
+    mut write: relay::RelaySink,
+    mut read: relay::RelayStream,
+    mut shutdown_rx: oneshot::Receiver<()>,
+) -> Result<(), String> {
+    let http_client = reqwest::Client::new();
+
+    loop {
+        let message = tokio::select! {
+            _ = &mut shutdown_rx => break,
+            message = read.next() => message,
+        };
+
+        let message = match message {
+            Some(Ok(message)) => message,
+            Some(Err(err)) => return Err(format!("Relay connection error: {}", err)),
+            None => break,
+        };
+
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let Ok(RelayMessage::ForwardRequest {
+            id,
+            method,
+            path,
+            body,
+            token: request_token,
+        }) = serde_json::from_str(&text)
+        else {
+            continue;
+        };
+
+        let reply = if request_token != token {
+            RelayReply::ForwardResponse {
+                id,
+                status: 401,
+                body: "invalid tunnel token".to_string(),
+            }
+        } else if !crate::backend::is_ready() {
+            RelayReply::ForwardResponse {
+                id,
+                status: 503,
+                body: "backend is not ready yet".to_string(),
+            }
+        } else {
+            let url = format!("{}{}", crate::backend::get_backend_url(), path);
+            let method = reqwest::Method::from_bytes(method.as_bytes())
+                .unwrap_or(reqwest::Method::GET);
+            let response = http_client.request(method, &url).body(body).send().await;
+            match response {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let body = response.text().await.unwrap_or_default();
+                    RelayReply::ForwardResponse { id, status, body }
+                }
+                Err(err) => RelayReply::ForwardResponse {
+                    id,
+                    status: 502,
+                    body: format!("backend forward failed: {}", err),
+                },
+            }
+        };
+
+        if let Ok(payload) = serde_json::to_string(&reply) {
+            if write.send(Message::Text(payload)).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    info!("Backend tunnel session closed");
+    Ok(())
+}
+
+/// Stop the tunnel, if running.
+#[tauri::command]
+pub fn stop_backend_tunnel() {
+    if let Some(sender) = SHUTDOWN.lock().unwrap().take() {
+        let _ = sender.send(());
+    }
+    TUNNEL_RUNNING.store(false, Ordering::SeqCst);
+    *PUBLIC_URL.lock().unwrap() = None;
+    info!("Backend tunnel stopped");
+}
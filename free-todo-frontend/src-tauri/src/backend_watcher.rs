@@ -0,0 +1,122 @@
+//! Dev-mode hot reload: restart the backend when its Python sources change.
+//!
+//! Watches the backend script root for `.py`/requirements changes, coalesces
+//! bursts of events into a single debounced reload, and restarts the backend
+//! through the existing backend supervisor rather than requiring a manual
+//! relaunch. A reload stops every pooled worker at once; workers are not
+//! restarted one at a time, so the backend is unavailable until the
+//! supervisors have re-spawned them.
+
+use crate::backend_log::emit_backend_log;
+use crate::backend_paths::get_backend_script_root;
+use crate::config::{self, ServerMode};
+use log::warn;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Start the watcher if we're in `ServerMode::Dev` and `backend.watch` isn't
+/// disabled. No-op otherwise.
+pub fn start(app: AppHandle) {
+    if ServerMode::current() != ServerMode::Dev {
+        return;
+    }
+    if !config::current().backend_watch {
+        return;
+    }
+
+    let backend_root = match get_backend_script_root(&app) {
+        Ok(root) => root,
+        Err(err) => {
+            warn!(
+                "Backend watcher disabled, could not resolve script root: {}",
+                err
+            );
+            return;
+        }
+    };
+
+    std::thread::spawn(move || watch_loop(app, backend_root));
+}
+
+fn watch_loop(app: AppHandle, backend_root: std::path::PathBuf) {
+    use notify::{RecursiveMode, Watcher};
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(move |event| {
+        let _ = tx.send(event);
+    }) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            warn!("Failed to start backend source watcher: {}", err);
+            return;
+        }
+    };
+
+    if let Err(err) = watcher.watch(&backend_root, RecursiveMode::Recursive) {
+        warn!("Failed to watch backend root {:?}: {}", backend_root, err);
+        return;
+    }
+
+    let mut pending_since: Option<Instant> = None;
+
+    loop {
+        let timeout = match pending_since {
+            Some(at) => DEBOUNCE.saturating_sub(at.elapsed()),
+            None => Duration::from_secs(3600),
+        };
+
+        match rx.recv_timeout(timeout) {
+            Ok(Ok(event)) => {
+                if is_relevant_change(&event) {
+                    pending_since.get_or_insert_with(Instant::now);
+                }
+            }
+            Ok(Err(err)) => warn!("Backend source watch error: {}", err),
+            Err(RecvTimeoutError::Timeout) => {
+                if let Some(at) = pending_since {
+                    if at.elapsed() >= DEBOUNCE {
+                        pending_since = None;
+                        trigger_reload(&app);
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn is_relevant_change(event: &notify::Event) -> bool {
+    use notify::EventKind;
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return false;
+    }
+
+    event.paths.iter().any(|path| {
+        path.extension().and_then(|ext| ext.to_str()) == Some("py")
+            || path.file_name().and_then(|name| name.to_str()) == Some("requirements.txt")
+    })
+}
+
+fn trigger_reload(app: &AppHandle) {
+    emit_backend_log(app, "Backend source change detected, reload triggered...");
+    crate::backend::restart_all_workers();
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let deadline = Instant::now() + Duration::from_secs(60);
+        while Instant::now() < deadline {
+            if crate::backend::is_ready() {
+                emit_backend_log(&app, "Backend reload complete");
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(200));
+        }
+        emit_backend_log(&app, "Backend reload timed out waiting for readiness");
+    });
+}
@@ -0,0 +1,127 @@
+//! FreeTodo CLI - scriptable control of an already-running instance.
+//!
+//! `freetodo shortcut <action>` detects the running GUI instance (the same
+//! way a worker supervisor detects an orphaned backend, via
+//! `detect_running_backend_port`) and POSTs the action to its local control
+//! endpoint instead of launching a second GUI. If no instance is found, it
+//! falls back to launching the GUI, matching the pattern used by keybinding
+//! daemons and shell scripts against other Tauri apps.
+
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, Subcommand};
+use free_todo::actions::ShortcutAction;
+use free_todo::backend_support::detect_running_backend_port;
+use free_todo::config::ServerMode;
+
+const ACTION_NAMES: [&str; 7] = [
+    "toggle-window",
+    "show-window",
+    "hide-window",
+    "start-recording",
+    "stop-recording",
+    "take-screenshot",
+    "view-screenshots",
+];
+
+#[cfg(windows)]
+const MAIN_BINARY_NAME: &str = "free-todo.exe";
+#[cfg(not(windows))]
+const MAIN_BINARY_NAME: &str = "free-todo";
+
+#[derive(Parser)]
+#[command(name = "freetodo", about = "Control a running FreeTodo instance")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Trigger an action (window toggle, recording, screenshot, ...) on the
+    /// already-running instance.
+    Shortcut {
+        #[arg(value_parser = PossibleValuesParser::new(ACTION_NAMES))]
+        action: String,
+    },
+}
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Shortcut { action }) => run_shortcut(&action),
+        None => {
+            if let Err(err) = launch_gui() {
+                log::error!("Failed to launch FreeTodo: {}", err);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_shortcut(action: &str) {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(err) => {
+            log::error!("Failed to start async runtime: {}", err);
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(err) = runtime.block_on(invoke_shortcut(action)) {
+        log::warn!("{} - launching the app instead", err);
+        if let Err(launch_err) = launch_gui() {
+            log::error!("Failed to launch FreeTodo: {}", launch_err);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Detect the running instance and POST `action` to its local control
+/// endpoint (served alongside the backend proxy, see `backend_proxy`).
+async fn invoke_shortcut(action: &str) -> Result<(), String> {
+    let action_name = action.replace('-', "_");
+    ShortcutAction::parse(&action_name).ok_or_else(|| format!("Unknown action: {}", action))?;
+
+    let mode = ServerMode::current();
+    // As a separate process, the CLI can't read the GUI's in-memory proxy
+    // state directly - detect the port it's actually bound to the same way a
+    // worker supervisor detects an orphaned backend, instead of assuming the
+    // configured default is what's really listening.
+    let port = detect_running_backend_port(mode)
+        .await
+        .ok_or_else(|| "No running FreeTodo instance detected".to_string())?;
+
+    let control_url = format!("http://127.0.0.1:{}/__app/shortcut", port);
+    let client = reqwest::Client::new();
+    let response = client
+        .post(&control_url)
+        .json(&serde_json::json!({ "action": action_name }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach running instance: {}", e))?;
+
+    if response.status().is_success() {
+        log::info!("Dispatched '{}' to the running instance", action);
+        Ok(())
+    } else {
+        Err(format!(
+            "Running instance rejected action: {}",
+            response.status()
+        ))
+    }
+}
+
+/// No running instance was found (or it didn't accept the action) - launch
+/// the GUI binary instead of failing outright.
+fn launch_gui() -> Result<(), String> {
+    let current_exe =
+        std::env::current_exe().map_err(|e| format!("Failed to resolve current exe: {}", e))?;
+    let gui_path = current_exe.with_file_name(MAIN_BINARY_NAME);
+
+    std::process::Command::new(&gui_path)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to launch {:?}: {}", gui_path, e))
+}
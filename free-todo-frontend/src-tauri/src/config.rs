@@ -5,12 +5,20 @@
 //! ## Window Modes
 //!
 //! The application supports two window modes (matching Electron):
-//! - **Web**: Standard window (1200x800, with decorations) - currently supported
-//! - **Island**: Transparent floating window - TODO: not yet implemented
+//! - **Web**: Standard window (1200x800, with decorations)
+//! - **Island**: Borderless, transparent, always-on-top window docked near
+//!   the top of the screen
 //!
-//! Tauri currently only builds the Web mode version.
+//! The active mode is persisted as `window_mode` and switched at runtime via
+//! `window_mode::set_window_mode`.
 
+use crate::WindowMode;
+use log::warn;
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use tauri::AppHandle;
 
 /// Server mode (development or production)
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -57,6 +65,14 @@ pub mod ports {
             ServerMode::Build => BUILD_BACKEND_PORT,
         }
     }
+
+    /// Dev mode backend worker port range (used to spread/detect pooled workers)
+    pub const DEV_BACKEND_RANGE_START: u16 = 8001;
+    pub const DEV_BACKEND_RANGE_END: u16 = 8020;
+
+    /// Build mode backend worker port range
+    pub const BUILD_BACKEND_RANGE_START: u16 = 8100;
+    pub const BUILD_BACKEND_RANGE_END: u16 = 8120;
 }
 
 /// Timeout configuration (in milliseconds)
@@ -72,6 +88,10 @@ pub mod timeouts {
 
     /// Health check retry interval (500ms)
     pub const HEALTH_CHECK_RETRY: u64 = 500;
+
+    /// Idle timeout for upgraded proxy tunnels (WebSocket/SSE), so a dead
+    /// backend doesn't leak sockets forever (5 minutes)
+    pub const UPGRADE_IDLE: u64 = 300_000;
 }
 
 /// Health check intervals (in milliseconds)
@@ -96,32 +116,598 @@ pub mod process {
     pub const BACKEND_DATA_DIR: &str = "lifetrace-data";
 }
 
-/// Get the default backend port based on environment or mode
-pub fn get_backend_port() -> u16 {
-    if let Ok(port) = env::var("BACKEND_PORT") {
-        if let Ok(p) = port.parse() {
-            return p;
+/// Get the default frontend port, resolved from the layered [`Config`]
+pub fn get_frontend_port() -> u16 {
+    current().frontend_port
+}
+
+/// Get frontend URL
+pub fn get_frontend_url() -> String {
+    format!("http://localhost:{}", get_frontend_port())
+}
+
+/// Backend runtime selected by `backend.runtime` in `lifetrace.toml` or by
+/// the `FREETODO_BACKEND_RUNTIME` env var.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendRuntimeKind {
+    Uv,
+    Script,
+    Pyinstaller,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct PortsSection {
+    frontend: Option<u16>,
+    backend: Option<u16>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct TimeoutsSection {
+    backend_ready_ms: Option<u64>,
+    frontend_ready_ms: Option<u64>,
+    health_check_ms: Option<u64>,
+    health_check_retry_ms: Option<u64>,
+    upgrade_idle_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct HealthCheckSection {
+    frontend_interval_ms: Option<u64>,
+    backend_interval_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct BackendSection {
+    runtime: Option<BackendRuntimeKind>,
+    workers: Option<usize>,
+    watch: Option<bool>,
+    interpreter_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct LogGatewaySection {
+    port: Option<u16>,
+    enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct BackendPathsSection {
+    /// Overrides `process::BACKEND_EXEC_NAME`.
+    exec_name: Option<String>,
+    /// Extra directories searched for the backend executable/script root,
+    /// ahead of the baked-in candidates.
+    extra_search_dirs: Vec<String>,
+    /// Overrides `process::BACKEND_DATA_DIR`.
+    data_dir_name: Option<String>,
+    /// Overrides the `-dev` suffix appended to the data dir name.
+    dev_suffix: Option<String>,
+    /// Overrides the `-build` suffix appended to the data dir name.
+    build_suffix: Option<String>,
+    /// Overrides the requirements file name looked up by
+    /// `backend_paths::get_requirements_path`.
+    requirements_filename: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct AssetsSection {
+    /// Directory the proxy serves static frontend assets from directly,
+    /// bypassing the backend hop entirely. Unset disables static serving.
+    root: Option<String>,
+    /// Path prefixes that always go to the backend instead, even if a file
+    /// happens to exist under `root` for them.
+    api_prefixes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct RawConfig {
+    ports: PortsSection,
+    timeouts: TimeoutsSection,
+    health_check: HealthCheckSection,
+    backend: BackendSection,
+    backend_paths: BackendPathsSection,
+    log_gateway: LogGatewaySection,
+    assets: AssetsSection,
+    /// `[shortcuts]` is a flat action-name -> accelerator-string map rather
+    /// than a fixed-field section, since the set of bindable actions is
+    /// open-ended (see `actions::ShortcutAction`).
+    shortcuts: std::collections::HashMap<String, String>,
+    start_on_login: Option<bool>,
+    window_mode: Option<WindowMode>,
+}
+
+/// Built-in shortcut bindings, used until the user rebinds them (at which
+/// point `shortcut::update_shortcut` persists the override into the
+/// `[shortcuts]` table).
+fn default_shortcuts() -> std::collections::HashMap<String, String> {
+    let mut bindings = std::collections::HashMap::new();
+    bindings.insert(
+        "toggle_window".to_string(),
+        "CommandOrControl+Shift+I".to_string(),
+    );
+    bindings
+}
+
+/// Layered runtime configuration.
+///
+/// Values are resolved as `built-in defaults < lifetrace.toml < environment
+/// variables`, in that order, and cached in [`CONFIG`] behind a `RwLock` so
+/// a file-watch can swap in a freshly parsed config atomically without
+/// restarting the app.
+///
+/// The sidecar reads its ports, health-check intervals and backend/proxy
+/// settings from here. `backend_ready_ms`, `frontend_ready_ms`,
+/// `health_check_ms` and `health_check_retry_ms` are resolved but not read
+/// anywhere: the readiness waits and health probes use the [`timeouts`]
+/// constants directly.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub frontend_port: u16,
+    pub backend_port: u16,
+    pub backend_ready_ms: u64,
+    pub frontend_ready_ms: u64,
+    pub health_check_ms: u64,
+    pub health_check_retry_ms: u64,
+    /// Idle timeout (ms) for upgraded proxy tunnels (WebSocket/SSE) before
+    /// `backend_proxy` closes them.
+    pub upgrade_idle_ms: u64,
+    pub frontend_interval_ms: u64,
+    pub backend_interval_ms: u64,
+    pub backend_runtime: Option<BackendRuntimeKind>,
+    /// Interpreter path resolved by the first-run setup wizard, persisted so
+    /// the Script runtime doesn't re-probe/fallback-cascade on every boot.
+    pub backend_interpreter_path: Option<String>,
+    /// Number of pooled backend worker processes the proxy load-balances across.
+    pub backend_workers: usize,
+    /// Whether to restart the backend when its Python sources change (dev mode only).
+    pub backend_watch: bool,
+    /// Backend executable name searched for by `backend_paths::get_backend_path`.
+    pub backend_exec_name: String,
+    /// Extra directories `backend_paths` searches for the backend
+    /// executable/script root, ahead of the baked-in candidates.
+    pub backend_extra_search_dirs: Vec<String>,
+    /// Backend data directory name, before the mode suffix is appended.
+    ///
+    /// `lifetrace.toml` lives *inside* the directory this resolves to, so a
+    /// file-layer override only takes effect on the second and later loads
+    /// (the config watcher's reload, or a later `config::load` call) - the
+    /// very first resolution of the data dir can only see the built-in
+    /// default or an env-var override, both available before the directory
+    /// - and therefore the file inside it - exist. Prefer the
+    /// `FREETODO_BACKEND_DATA_DIR_NAME` env var if this needs to differ from
+    /// the default on first launch.
+    pub backend_data_dir_name: String,
+    /// Suffix appended to `backend_data_dir_name` in dev mode.
+    pub backend_dev_suffix: String,
+    /// Suffix appended to `backend_data_dir_name` in build mode.
+    pub backend_build_suffix: String,
+    /// Requirements file name looked up by `backend_paths::get_requirements_path`.
+    pub backend_requirements_filename: String,
+    /// Port the log gateway's WebSocket endpoint listens on.
+    pub log_gateway_port: u16,
+    /// Whether the log gateway (WebSocket + local socket) is started at all.
+    pub log_gateway_enabled: bool,
+    /// Named shortcut action -> accelerator string, loaded from the
+    /// `[shortcuts]` table and rebindable at runtime via
+    /// `shortcut::update_shortcut`.
+    pub shortcuts: std::collections::HashMap<String, String>,
+    /// Whether the app should register itself to launch on login, via the
+    /// `autostart` module.
+    pub start_on_login: bool,
+    /// Which window is currently active, switched at runtime via
+    /// `window_mode::set_window_mode`.
+    pub window_mode: WindowMode,
+    /// Directory `backend_proxy` serves static frontend assets from
+    /// directly. `None` disables static serving entirely, so every request
+    /// falls through to the backend as before.
+    pub asset_root: Option<PathBuf>,
+    /// Path prefixes that always go to the backend rather than being
+    /// resolved against `asset_root`.
+    pub api_prefixes: Vec<String>,
+}
+
+/// Prefixes that always route to the backend: the REST API, the proxy's own
+/// `/ready` probe, and its `/__app/*` control routes.
+fn default_api_prefixes() -> Vec<String> {
+    vec!["/api".to_string(), "/ready".to_string(), "/__app".to_string()]
+}
+
+impl Config {
+    fn from_layers(file: RawConfig) -> Self {
+        let mode = ServerMode::current();
+        let mut cfg = Config {
+            frontend_port: ports::frontend_port(mode),
+            backend_port: ports::backend_port(mode),
+            backend_ready_ms: timeouts::BACKEND_READY,
+            frontend_ready_ms: timeouts::FRONTEND_READY,
+            health_check_ms: timeouts::HEALTH_CHECK,
+            health_check_retry_ms: timeouts::HEALTH_CHECK_RETRY,
+            upgrade_idle_ms: timeouts::UPGRADE_IDLE,
+            frontend_interval_ms: health_check::FRONTEND_INTERVAL,
+            backend_interval_ms: health_check::BACKEND_INTERVAL,
+            backend_runtime: None,
+            backend_interpreter_path: None,
+            backend_workers: 1,
+            backend_watch: matches!(mode, ServerMode::Dev),
+            backend_exec_name: process::BACKEND_EXEC_NAME.to_string(),
+            backend_extra_search_dirs: Vec::new(),
+            backend_data_dir_name: process::BACKEND_DATA_DIR.to_string(),
+            backend_dev_suffix: "dev".to_string(),
+            backend_build_suffix: "build".to_string(),
+            backend_requirements_filename: "requirements-runtime.txt".to_string(),
+            log_gateway_port: 8787,
+            log_gateway_enabled: true,
+            shortcuts: default_shortcuts(),
+            start_on_login: false,
+            window_mode: WindowMode::default(),
+            asset_root: None,
+            api_prefixes: default_api_prefixes(),
+        };
+
+        // Layer: config file
+        if let Some(v) = file.ports.frontend {
+            cfg.frontend_port = v;
+        }
+        if let Some(v) = file.ports.backend {
+            cfg.backend_port = v;
+        }
+        if let Some(v) = file.timeouts.backend_ready_ms {
+            cfg.backend_ready_ms = v;
+        }
+        if let Some(v) = file.timeouts.frontend_ready_ms {
+            cfg.frontend_ready_ms = v;
+        }
+        if let Some(v) = file.timeouts.health_check_ms {
+            cfg.health_check_ms = v;
+        }
+        if let Some(v) = file.timeouts.health_check_retry_ms {
+            cfg.health_check_retry_ms = v;
+        }
+        if let Some(v) = file.timeouts.upgrade_idle_ms {
+            cfg.upgrade_idle_ms = v;
+        }
+        if let Some(v) = file.health_check.frontend_interval_ms {
+            cfg.frontend_interval_ms = v;
+        }
+        if let Some(v) = file.health_check.backend_interval_ms {
+            cfg.backend_interval_ms = v;
+        }
+        if let Some(v) = file.backend.runtime {
+            cfg.backend_runtime = Some(v);
+        }
+        if let Some(v) = file.backend.interpreter_path {
+            cfg.backend_interpreter_path = Some(v);
+        }
+        if let Some(v) = file.backend.workers {
+            cfg.backend_workers = v;
+        }
+        if let Some(v) = file.backend.watch {
+            cfg.backend_watch = v;
+        }
+        if let Some(v) = file.backend_paths.exec_name {
+            cfg.backend_exec_name = v;
+        }
+        if !file.backend_paths.extra_search_dirs.is_empty() {
+            cfg.backend_extra_search_dirs = file.backend_paths.extra_search_dirs;
+        }
+        if let Some(v) = file.backend_paths.data_dir_name {
+            cfg.backend_data_dir_name = v;
+        }
+        if let Some(v) = file.backend_paths.dev_suffix {
+            cfg.backend_dev_suffix = v;
+        }
+        if let Some(v) = file.backend_paths.build_suffix {
+            cfg.backend_build_suffix = v;
+        }
+        if let Some(v) = file.backend_paths.requirements_filename {
+            cfg.backend_requirements_filename = v;
+        }
+        if let Some(v) = file.log_gateway.port {
+            cfg.log_gateway_port = v;
+        }
+        if let Some(v) = file.log_gateway.enabled {
+            cfg.log_gateway_enabled = v;
+        }
+        for (action, accelerator) in file.shortcuts {
+            cfg.shortcuts.insert(action, accelerator);
+        }
+        if let Some(v) = file.start_on_login {
+            cfg.start_on_login = v;
+        }
+        if let Some(v) = file.window_mode {
+            cfg.window_mode = v;
+        }
+        if let Some(v) = file.assets.root {
+            cfg.asset_root = Some(PathBuf::from(v));
+        }
+        if let Some(v) = file.assets.api_prefixes {
+            cfg.api_prefixes = v;
         }
+
+        // Layer: environment variables (outranks the config file)
+        if let Ok(v) = env::var("PORT") {
+            if let Ok(p) = v.parse() {
+                cfg.frontend_port = p;
+            }
+        }
+        if let Ok(v) = env::var("BACKEND_PORT") {
+            if let Ok(p) = v.parse() {
+                cfg.backend_port = p;
+            }
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_RUNTIME") {
+            cfg.backend_runtime = match v.to_lowercase().as_str() {
+                "uv" | "uv-run" | "uvrun" => Some(BackendRuntimeKind::Uv),
+                "script" => Some(BackendRuntimeKind::Script),
+                "pyinstaller" => Some(BackendRuntimeKind::Pyinstaller),
+                _ => cfg.backend_runtime,
+            };
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_WORKERS") {
+            if let Ok(n) = v.parse() {
+                cfg.backend_workers = n;
+            }
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_WATCH") {
+            cfg.backend_watch = matches!(v.as_str(), "1" | "true" | "TRUE" | "True");
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_EXEC_NAME") {
+            cfg.backend_exec_name = v;
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_EXTRA_SEARCH_DIRS") {
+            cfg.backend_extra_search_dirs = v.split(',').map(|s| s.trim().to_string()).collect();
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_DATA_DIR_NAME") {
+            cfg.backend_data_dir_name = v;
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_DEV_SUFFIX") {
+            cfg.backend_dev_suffix = v;
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_BUILD_SUFFIX") {
+            cfg.backend_build_suffix = v;
+        }
+        if let Ok(v) = env::var("FREETODO_BACKEND_REQUIREMENTS_FILENAME") {
+            cfg.backend_requirements_filename = v;
+        }
+        if let Ok(v) = env::var("FREETODO_LOG_GATEWAY_PORT") {
+            if let Ok(p) = v.parse() {
+                cfg.log_gateway_port = p;
+            }
+        }
+        if let Ok(v) = env::var("FREETODO_UPGRADE_IDLE_MS") {
+            if let Ok(ms) = v.parse() {
+                cfg.upgrade_idle_ms = ms;
+            }
+        }
+        if let Ok(v) = env::var("FREETODO_START_ON_LOGIN") {
+            cfg.start_on_login = matches!(v.as_str(), "1" | "true" | "TRUE" | "True");
+        }
+        if let Ok(v) = env::var("FREETODO_WINDOW_MODE") {
+            cfg.window_mode = match v.to_lowercase().as_str() {
+                "web" => WindowMode::Web,
+                "island" => WindowMode::Island,
+                _ => cfg.window_mode,
+            };
+        }
+        if let Ok(v) = env::var("FREETODO_ASSET_ROOT") {
+            cfg.asset_root = Some(PathBuf::from(v));
+        }
+        if let Ok(v) = env::var("FREETODO_API_PREFIXES") {
+            cfg.api_prefixes = v.split(',').map(|s| s.trim().to_string()).collect();
+        }
+
+        cfg
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.frontend_port == 0 || self.backend_port == 0 {
+            return Err("ports must be non-zero".to_string());
+        }
+        if self.frontend_port == self.backend_port {
+            return Err("frontend and backend ports must be distinct".to_string());
+        }
+        if self.backend_workers == 0 {
+            return Err("backend.workers must be at least 1".to_string());
+        }
+        if self.log_gateway_enabled
+            && (self.log_gateway_port == self.frontend_port
+                || self.log_gateway_port == self.backend_port)
+        {
+            return Err("log_gateway.port must differ from the frontend/backend ports".to_string());
+        }
+        Ok(())
     }
-    ports::backend_port(ServerMode::current())
 }
 
-/// Get the default frontend port based on environment or mode
-pub fn get_frontend_port() -> u16 {
-    if let Ok(port) = env::var("PORT") {
-        if let Ok(p) = port.parse() {
-            return p;
+impl Default for Config {
+    fn default() -> Self {
+        Config::from_layers(RawConfig::default())
+    }
+}
+
+static CONFIG: OnceLock<RwLock<Config>> = OnceLock::new();
+
+fn config_lock() -> &'static RwLock<Config> {
+    CONFIG.get_or_init(|| RwLock::new(Config::default()))
+}
+
+/// Path to the layered config file inside the backend data directory.
+pub fn config_file_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("lifetrace.toml")
+}
+
+fn read_raw_config(path: &Path) -> Result<RawConfig, String> {
+    let text =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {:?}: {}", path, e))?;
+    toml::from_str(&text).map_err(|e| format!("Failed to parse {:?}: {}", path, e))
+}
+
+/// Load `lifetrace.toml` from `data_dir` (if present) and resolve it into the
+/// active [`Config`]. Falls back to built-in defaults when the file is
+/// missing or malformed.
+pub fn load(data_dir: &Path) {
+    let raw = read_raw_config(&config_file_path(data_dir)).unwrap_or_default();
+    let resolved = Config::from_layers(raw);
+    if let Err(err) = resolved.validate() {
+        warn!("Ignoring invalid config at startup: {}", err);
+        return;
+    }
+    *config_lock().write().unwrap() = resolved;
+}
+
+/// Re-resolve the config file, swapping it in atomically on success and
+/// falling back to the previous good config on parse/validation error.
+pub fn reload(app: &AppHandle, data_dir: &Path) {
+    let path = config_file_path(data_dir);
+    match read_raw_config(&path) {
+        Ok(raw) => {
+            let resolved = Config::from_layers(raw);
+            match resolved.validate() {
+                Ok(()) => {
+                    *config_lock().write().unwrap() = resolved;
+                    crate::backend_log::emit_backend_log(app, "Configuration reloaded");
+                }
+                Err(err) => {
+                    crate::backend_log::emit_backend_log(
+                        app,
+                        format!("Ignoring invalid config reload, keeping previous config: {}", err),
+                    );
+                }
+            }
+        }
+        Err(err) => {
+            crate::backend_log::emit_backend_log(
+                app,
+                format!("Config reload failed, keeping previous config: {}", err),
+            );
         }
     }
-    ports::frontend_port(ServerMode::current())
 }
 
-/// Get backend URL
-pub fn get_backend_url() -> String {
-    format!("http://127.0.0.1:{}", get_backend_port())
+/// Get the current resolved configuration.
+pub fn current() -> Config {
+    config_lock().read().unwrap().clone()
 }
 
-/// Get frontend URL
-pub fn get_frontend_url() -> String {
-    format!("http://localhost:{}", get_frontend_port())
+/// Read `lifetrace.toml`, hand the parsed table to `mutate` to apply a
+/// single change, then write it back out and reload the active config so
+/// the change takes effect immediately. Shared by the one-field persist
+/// helpers below, which previously each repeated this read/mutate/write/
+/// reload sequence with only the mutation differing.
+fn persist_with<F>(app: &AppHandle, mutate: F) -> Result<(), String>
+where
+    F: FnOnce(&mut toml::value::Table) -> Result<(), String>,
+{
+    let data_dir = crate::backend_paths::get_data_dir(app, ServerMode::current())?;
+    let path = config_file_path(&data_dir);
+
+    let mut doc: toml::Value = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|text| toml::from_str(&text).ok())
+        .unwrap_or_else(|| toml::Value::Table(Default::default()));
+
+    let table = doc
+        .as_table_mut()
+        .ok_or_else(|| "lifetrace.toml is malformed (expected a table)".to_string())?;
+    mutate(table)?;
+
+    let serialized =
+        toml::to_string_pretty(&doc).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    std::fs::write(&path, serialized).map_err(|e| format!("Failed to write config: {}", e))?;
+
+    reload(app, &data_dir);
+    Ok(())
+}
+
+/// Persist a single shortcut binding into `lifetrace.toml`'s `[shortcuts]`
+/// table (leaving every other section untouched) and reload the active
+/// config so it takes effect immediately. Called by
+/// `shortcut::update_shortcut` after the new binding has already been
+/// registered successfully.
+pub fn persist_shortcut(app: &AppHandle, action: &str, accelerator: &str) -> Result<(), String> {
+    let action = action.to_string();
+    let accelerator = accelerator.to_string();
+    persist_with(app, move |table| {
+        let shortcuts_table = table
+            .entry("shortcuts")
+            .or_insert_with(|| toml::Value::Table(Default::default()))
+            .as_table_mut()
+            .ok_or_else(|| "lifetrace.toml [shortcuts] section is malformed".to_string())?;
+        shortcuts_table.insert(action, toml::Value::String(accelerator));
+        Ok(())
+    })
+}
+
+/// Persist the `start_on_login` flag into `lifetrace.toml` (leaving every
+/// other section untouched) and reload the active config so it takes effect
+/// immediately. Called by `autostart::set_start_on_login` after the OS-level
+/// registration has already been applied successfully.
+pub fn persist_start_on_login(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    persist_with(app, move |table| {
+        table.insert("start_on_login".to_string(), toml::Value::Boolean(enabled));
+        Ok(())
+    })
+}
+
+/// Persist the `window_mode` flag into `lifetrace.toml` (leaving every
+/// other section untouched) and reload the active config so it takes
+/// effect immediately. Called by `window_mode::set_window_mode` before it
+/// shows/hides the corresponding windows.
+pub fn persist_window_mode(app: &AppHandle, mode: WindowMode) -> Result<(), String> {
+    let mode_str = match mode {
+        WindowMode::Web => "web",
+        WindowMode::Island => "island",
+    };
+    persist_with(app, move |table| {
+        table.insert(
+            "window_mode".to_string(),
+            toml::Value::String(mode_str.to_string()),
+        );
+        Ok(())
+    })
+}
+
+/// Watch `lifetrace.toml` for changes and hot-reload the config on write.
+pub fn watch(app: AppHandle, data_dir: PathBuf) {
+    std::thread::spawn(move || {
+        use notify::{RecursiveMode, Watcher};
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        }) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                warn!("Failed to start config file watcher: {}", err);
+                return;
+            }
+        };
+
+        // Watch the containing directory: the config file may not exist yet,
+        // and editors commonly replace it via rename-on-save.
+        if let Err(err) = watcher.watch(&data_dir, RecursiveMode::NonRecursive) {
+            warn!("Failed to watch config directory {:?}: {}", data_dir, err);
+            return;
+        }
+
+        for event in rx {
+            let Ok(event) = event else { continue };
+            let touches_config = event
+                .paths
+                .iter()
+                .any(|path| path.file_name() == Some(std::ffi::OsStr::new("lifetrace.toml")));
+            if touches_config {
+                reload(&app, &data_dir);
+            }
+        }
+    });
 }
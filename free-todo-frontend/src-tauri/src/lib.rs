@@ -6,34 +6,52 @@
 //! ## Window Modes
 //!
 //! The application supports two window modes (matching Electron implementation):
-//! - **Web Mode**: Standard window with decorations (currently implemented)
-//! - **Island Mode**: Transparent floating window like Dynamic Island (TODO: not yet implemented)
+//! - **Web Mode**: Standard window with decorations
+//! - **Island Mode**: Borderless, transparent, always-on-top window docked
+//!   near the top of the screen, like a Dynamic Island
 //!
-//! Currently only Web mode is supported. Island mode is still in development.
+//! Both windows are created during `.setup(...)`; only the active mode's
+//! window is shown, and `set_window_mode` switches between them at runtime.
+//! See the `window_mode` module.
 
+pub mod actions;
+pub mod autostart;
 pub mod backend;
+pub mod backend_log;
+pub mod backend_paths;
+pub mod backend_proxy;
+pub mod backend_python;
+pub mod backend_support;
+pub mod backend_tunnel;
+pub mod backend_watcher;
 pub mod config;
+pub mod log_gateway;
 pub mod nextjs;
+pub mod recording;
+pub mod relay;
+pub mod setup_wizard;
 pub mod shortcut;
 pub mod tray;
+pub mod tunnel;
+pub mod visibility;
+pub mod window_mode;
 
 use log::info;
-use tauri::Manager;
+use serde::{Deserialize, Serialize};
 
-/// Window mode configuration
-/// Currently only Web mode is supported
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-#[allow(dead_code)]
+/// Which window is currently active. See the module doc above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum WindowMode {
-    /// Standard window with decorations (default, currently supported)
+    /// Standard window with decorations (default)
     #[default]
     Web,
-    /// Transparent floating window like Dynamic Island (TODO: not yet implemented)
+    /// Borderless, transparent, always-on-top window docked near the top
+    /// of the screen
     Island,
 }
 
 /// Initialize the Tauri application with all required plugins and setup
-/// Note: Currently only Web mode is supported
 pub fn run() {
     // Initialize logger
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
@@ -49,6 +67,31 @@ pub fn run() {
 
             info!("Application setup starting...");
 
+            // Register the managed window-visibility state used by the
+            // tray, shortcuts, and the commands below
+            visibility::init(&handle);
+
+            // Load the layered lifetrace.toml config and watch it for live reload
+            if let Ok(data_dir) = backend_paths::get_data_dir(&handle, config::ServerMode::current())
+            {
+                config::load(&data_dir);
+                config::watch(handle.clone(), data_dir);
+            }
+
+            // Create the island window (hidden unless Island mode is
+            // active) and show whichever window the loaded config selects
+            window_mode::init(&handle)?;
+
+            // Start the log gateway (WebSocket + local socket) so external
+            // tools can subscribe to the live backend-log stream
+            let gateway_config = config::current();
+            if gateway_config.log_gateway_enabled {
+                let gateway_port = gateway_config.log_gateway_port;
+                tauri::async_runtime::spawn(async move {
+                    log_gateway::start(gateway_port);
+                });
+            }
+
             // Start Python backend
             let backend_handle = handle.clone();
             tauri::async_runtime::spawn(async move {
@@ -57,6 +100,10 @@ pub fn run() {
                 }
             });
 
+            // Restart the backend automatically when its Python sources
+            // change (dev mode only, gated by `backend.watch`)
+            backend_watcher::start(handle.clone());
+
             // Start Next.js server (only in release mode)
             #[cfg(not(debug_assertions))]
             {
@@ -74,6 +121,21 @@ pub fn run() {
             // Setup global shortcuts
             shortcut::setup_shortcuts(app)?;
 
+            // Keep the tray's recording menu items in sync with whatever
+            // the backend actually reports on its /health endpoint
+            recording::start_polling(handle.clone());
+
+            // Bring the OS-level launch-on-login registration in line with
+            // the persisted config. Non-fatal: collect failures instead of
+            // aborting startup over a missing autostart backend.
+            let mut setup_warnings = Vec::new();
+            if let Err(err) = autostart::sync_with_config(config::current().start_on_login) {
+                setup_warnings.push(format!("Launch on login: {}", err));
+            }
+            for warning in setup_warnings {
+                backend_log::emit_labeled_log(&handle, "startup", "warn", warning);
+            }
+
             info!("Application setup completed");
 
             Ok(())
@@ -84,6 +146,14 @@ pub fn run() {
             toggle_window,
             show_window,
             hide_window,
+            backend_tunnel::start_backend_tunnel,
+            backend_tunnel::stop_backend_tunnel,
+            setup_wizard::run_backend_diagnostics,
+            setup_wizard::needs_first_run_setup,
+            setup_wizard::select_backend_runtime,
+            autostart::get_start_on_login,
+            autostart::set_start_on_login,
+            window_mode::set_window_mode,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -98,7 +168,7 @@ fn get_backend_url() -> String {
 /// Get backend server health status
 #[tauri::command]
 async fn get_backend_status() -> Result<bool, String> {
-    backend::check_backend_health(config::get_backend_port())
+    backend::check_backend_health(backend::get_backend_port())
         .await
         .map_err(|e| e.to_string())
 }
@@ -106,29 +176,17 @@ async fn get_backend_status() -> Result<bool, String> {
 /// Toggle main window visibility
 #[tauri::command]
 fn toggle_window(app: tauri::AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        if window.is_visible().unwrap_or(false) {
-            let _ = window.hide();
-        } else {
-            let _ = window.show();
-            let _ = window.set_focus();
-        }
-    }
+    visibility::toggle(&app);
 }
 
 /// Show main window
 #[tauri::command]
 fn show_window(app: tauri::AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let _ = window.show();
-        let _ = window.set_focus();
-    }
+    visibility::show(&app);
 }
 
 /// Hide main window
 #[tauri::command]
 fn hide_window(app: tauri::AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let _ = window.hide();
-    }
+    visibility::hide(&app);
 }
@@ -0,0 +1,256 @@
+//! Pluggable log gateway.
+//!
+//! `emit_backend_log`/`spawn_log_reader` only ever fanned log lines out to
+//! the `log` crate and a single Tauri `backend-log` event. This module lets
+//! external tools subscribe to the live stream too: every line is wrapped in
+//! a structured [`LogRecord`] and broadcast over (a) a WebSocket endpoint on
+//! a configurable port and (b) a local Unix-domain socket (named pipe on
+//! Windows), each message wrapped in a minimal JSON-RPC 2.0 notification
+//! envelope. A bounded replay buffer means a tool attaching mid-startup
+//! still sees the last few lines of context (e.g. "Backend ready").
+
+use axum::{
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use log::warn;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::broadcast;
+
+const REPLAY_CAPACITY: usize = 200;
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A single structured log line, as broadcast to gateway subscribers.
+#[derive(Clone, Debug, Serialize)]
+pub struct LogRecord {
+    pub ts: u64,
+    pub label: String,
+    pub level: String,
+    pub message: String,
+}
+
+/// Minimal JSON-RPC 2.0 notification envelope wrapping a [`LogRecord`].
+#[derive(Serialize)]
+struct LogNotification<'a> {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: &'a LogRecord,
+}
+
+fn envelope(record: &LogRecord) -> String {
+    let notification = LogNotification {
+        jsonrpc: "2.0",
+        method: "log",
+        params: record,
+    };
+    serde_json::to_string(&notification).unwrap_or_default()
+}
+
+struct Gateway {
+    sender: broadcast::Sender<LogRecord>,
+    replay: Mutex<VecDeque<LogRecord>>,
+}
+
+static GATEWAY: OnceLock<Gateway> = OnceLock::new();
+
+fn gateway() -> &'static Gateway {
+    GATEWAY.get_or_init(|| Gateway {
+        sender: broadcast::channel(CHANNEL_CAPACITY).0,
+        replay: Mutex::new(VecDeque::with_capacity(REPLAY_CAPACITY)),
+    })
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Publish a log line to every gateway subscriber (WebSocket, socket, and
+/// the replay buffer for late joiners). Does not touch the Tauri event.
+pub fn publish(label: &str, level: &str, message: &str) {
+    let record = LogRecord {
+        ts: now_ms(),
+        label: label.to_string(),
+        level: level.to_string(),
+        message: message.to_string(),
+    };
+
+    let gateway = gateway();
+    {
+        let mut replay = gateway.replay.lock().unwrap();
+        if replay.len() >= REPLAY_CAPACITY {
+            replay.pop_front();
+        }
+        replay.push_back(record.clone());
+    }
+    // Sending fails only when there are no subscribers, which isn't an error.
+    let _ = gateway.sender.send(record);
+}
+
+fn subscribe() -> (Vec<LogRecord>, broadcast::Receiver<LogRecord>) {
+    let gateway = gateway();
+    let replay = gateway.replay.lock().unwrap().iter().cloned().collect();
+    (replay, gateway.sender.subscribe())
+}
+
+/// Start the WebSocket and local-socket gateway listeners. Failures are
+/// logged and non-fatal; the rest of the log pipeline keeps working either
+/// way.
+pub fn start(port: u16) {
+    tokio::spawn(async move {
+        if let Err(err) = start_ws_server(port).await {
+            warn!("Log gateway WebSocket server failed to start: {}", err);
+        }
+    });
+    tokio::spawn(async move {
+        if let Err(err) = start_socket_server().await {
+            warn!("Log gateway socket server failed to start: {}", err);
+        }
+    });
+}
+
+async fn start_ws_server(port: u16) -> Result<(), String> {
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+        .await
+        .map_err(|e| format!("Failed to bind log gateway port {}: {}", port, e))?;
+
+    let app = Router::new().route("/", get(ws_handler));
+
+    tokio::spawn(async move {
+        if let Err(err) = axum::serve(listener, app).await {
+            warn!("Log gateway WebSocket server exited: {}", err);
+        }
+    });
+
+    Ok(())
+}
+
+async fn ws_handler(ws: WebSocketUpgrade) -> impl IntoResponse {
+    ws.on_upgrade(serve_ws_subscriber)
+}
+
+async fn serve_ws_subscriber(mut socket: WebSocket) {
+    let (replay, mut rx) = subscribe();
+    for record in &replay {
+        if socket.send(Message::Text(envelope(record))).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        tokio::select! {
+            record = rx.recv() => {
+                match record {
+                    Ok(record) => {
+                        if socket.send(Message::Text(envelope(&record))).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            incoming = socket.recv() => {
+                if incoming.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+fn socket_path() -> std::path::PathBuf {
+    std::env::temp_dir().join("freetodo-backend-log.sock")
+}
+
+#[cfg(unix)]
+async fn start_socket_server() -> Result<(), String> {
+    use tokio::net::UnixListener;
+
+    let path = socket_path();
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path)
+        .map_err(|e| format!("Failed to bind log gateway socket {:?}: {}", path, e))?;
+
+    tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(serve_socket_subscriber(stream));
+                }
+                Err(err) => {
+                    warn!("Log gateway socket accept failed: {}", err);
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn pipe_name() -> &'static str {
+    r"\\.\pipe\freetodo-backend-log"
+}
+
+#[cfg(windows)]
+async fn start_socket_server() -> Result<(), String> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    tokio::spawn(async move {
+        loop {
+            let server = match ServerOptions::new().create(pipe_name()) {
+                Ok(server) => server,
+                Err(err) => {
+                    warn!("Log gateway named pipe create failed: {}", err);
+                    break;
+                }
+            };
+            if server.connect().await.is_err() {
+                continue;
+            }
+            tokio::spawn(serve_socket_subscriber(server));
+        }
+    });
+
+    Ok(())
+}
+
+async fn serve_socket_subscriber(mut stream: impl tokio::io::AsyncWrite + Unpin) {
+    let (replay, mut rx) = subscribe();
+    for record in &replay {
+        if write_line(&mut stream, &envelope(record)).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        match rx.recv().await {
+            Ok(record) => {
+                if write_line(&mut stream, &envelope(&record)).await.is_err() {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+async fn write_line(
+    stream: &mut (impl tokio::io::AsyncWrite + Unpin),
+    line: &str,
+) -> std::io::Result<()> {
+    stream.write_all(line.as_bytes()).await?;
+    stream.write_all(b"\n").await
+}
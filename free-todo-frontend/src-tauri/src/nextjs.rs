@@ -9,7 +9,7 @@ use log::{error, info, warn};
 use reqwest::Client;
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
-use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, Ordering};
 use std::sync::Mutex;
 use std::time::Duration;
 use tauri::{AppHandle, Manager};
@@ -23,6 +23,15 @@ static FRONTEND_PORT: AtomicU16 = AtomicU16::new(3001);
 /// Flag indicating if server is stopping
 static IS_STOPPING: AtomicBool = AtomicBool::new(false);
 
+/// Consecutive restart attempts since the last successful health probe
+static RESTART_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+/// Give up auto-restarting after this many consecutive failures
+const MAX_CONSECUTIVE_RESTARTS: u32 = 5;
+
+/// Consecutive failed health probes to tolerate before treating the server as dead
+const MAX_CONSECUTIVE_HEALTH_FAILURES: u32 = 3;
+
 /// Get the frontend URL
 pub fn get_frontend_url() -> String {
     let port = FRONTEND_PORT.load(Ordering::Relaxed);
@@ -143,6 +152,31 @@ pub async fn start_nextjs(app: &AppHandle) -> Result<(), Box<dyn std::error::Err
     }
 
     // Production mode: start standalone server
+    let port = spawn_nextjs_process(app).await?;
+
+    // Start health check loop, which also supervises and restarts the process
+    start_health_check_loop(app.clone(), port);
+
+    if tunnel_enabled() {
+        if let Err(err) = crate::tunnel::start_tunnel(app.clone()).await {
+            warn!("Failed to start remote access tunnel: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the relay tunnel should be opened once the frontend is ready.
+fn tunnel_enabled() -> bool {
+    std::env::var("FREETODO_TUNNEL")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Spawn the Next.js standalone server and wait for it to become ready,
+/// returning the port it's listening on. Used both for the initial launch and
+/// for restarts triggered by the supervisor loop.
+async fn spawn_nextjs_process(app: &AppHandle) -> Result<u16, Box<dyn std::error::Error + Send + Sync>> {
     info!("Starting Next.js production server...");
 
     // Get server path
@@ -197,10 +231,7 @@ pub async fn start_nextjs(app: &AppHandle) -> Result<(), Box<dyn std::error::Err
     wait_for_server(&server_url, timeouts::FRONTEND_READY).await?;
     info!("Next.js server is ready at {}", server_url);
 
-    // Start health check loop
-    start_health_check_loop(port);
-
-    Ok(())
+    Ok(port)
 }
 
 /// Find Node.js executable
@@ -250,9 +281,13 @@ fn which_node() -> Result<PathBuf, String> {
 }
 
 /// Start health check loop
-fn start_health_check_loop(port: u16) {
+/// Supervise the Next.js process: probe its health and, once it has failed
+/// enough consecutive checks or the process itself has exited, respawn it
+/// with exponential backoff. Gives up after `MAX_CONSECUTIVE_RESTARTS`.
+fn start_health_check_loop(app: AppHandle, mut port: u16) {
     tokio::spawn(async move {
-        let interval = Duration::from_millis(config::health_check::FRONTEND_INTERVAL);
+        let interval = Duration::from_millis(config::current().frontend_interval_ms);
+        let mut consecutive_failures = 0u32;
 
         loop {
             tokio::time::sleep(interval).await;
@@ -261,13 +296,96 @@ fn start_health_check_loop(port: u16) {
                 break;
             }
 
-            if !check_server_health(port).await {
-                warn!("Next.js health check failed");
+            let process_exited = {
+                let mut guard = NEXTJS_PROCESS.lock().unwrap();
+                match guard.as_mut() {
+                    Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                    None => true,
+                }
+            };
+
+            let healthy = !process_exited && check_server_health(port).await;
+
+            if healthy {
+                consecutive_failures = 0;
+                RESTART_ATTEMPTS.store(0, Ordering::Relaxed);
+                continue;
+            }
+
+            consecutive_failures += 1;
+            warn!(
+                "Next.js health check failed ({}/{})",
+                consecutive_failures, MAX_CONSECUTIVE_HEALTH_FAILURES
+            );
+
+            if consecutive_failures < MAX_CONSECUTIVE_HEALTH_FAILURES && !process_exited {
+                continue;
+            }
+
+            if IS_STOPPING.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let attempt = RESTART_ATTEMPTS.fetch_add(1, Ordering::Relaxed) + 1;
+            if attempt > MAX_CONSECUTIVE_RESTARTS {
+                error!(
+                    "Next.js failed to recover after {} restart attempts, giving up",
+                    MAX_CONSECUTIVE_RESTARTS
+                );
+                break;
+            }
+
+            let backoff = Duration::from_secs(1u64 << (attempt - 1).min(6));
+            warn!(
+                "Restarting Next.js server (attempt {}/{}) after {:?}",
+                attempt, MAX_CONSECUTIVE_RESTARTS, backoff
+            );
+            tokio::time::sleep(backoff).await;
+
+            if IS_STOPPING.load(Ordering::Relaxed) {
+                break;
+            }
+
+            stop_managed_process().await;
+            match spawn_nextjs_process(&app).await {
+                Ok(new_port) => {
+                    port = new_port;
+                    consecutive_failures = 0;
+                    info!("Next.js server restarted on port {}", port);
+                }
+                Err(err) => {
+                    error!("Failed to restart Next.js server: {}", err);
+                }
             }
         }
     });
 }
 
+/// Kill the managed Next.js process without setting `IS_STOPPING`, used by
+/// the supervisor before a restart. Async (unlike `stop_nextjs`) because it
+/// runs inline in the supervisor's tokio task - a blocking sleep here would
+/// stall that task's worker thread instead of just yielding.
+async fn stop_managed_process() {
+    let child = {
+        let mut guard = NEXTJS_PROCESS.lock().unwrap();
+        guard.take()
+    };
+    if let Some(mut child) = child {
+        #[cfg(unix)]
+        {
+            unsafe {
+                libc::kill(child.id() as i32, libc::SIGTERM);
+            }
+        }
+        #[cfg(windows)]
+        {
+            let _ = child.kill();
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let _ = child.kill();
+    }
+}
+
 /// Stop the Next.js server
 pub fn stop_nextjs() {
     IS_STOPPING.store(true, Ordering::Relaxed);
@@ -311,5 +429,6 @@ pub fn stop_nextjs() {
 
 /// Cleanup on application exit
 pub fn cleanup() {
+    crate::tunnel::stop_tunnel();
     stop_nextjs();
 }
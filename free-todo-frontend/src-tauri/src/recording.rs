@@ -0,0 +1,127 @@
+//! Recording control.
+//!
+//! Wires the tray's recording/screenshot menu items (reached through
+//! `actions::dispatch`, same as the window actions) to the Python backend's
+//! `/recording/*` and `/screenshot` endpoints, and polls `/health` so the
+//! tray's enabled/disabled state - and the frontend - stay in sync with
+//! whatever the backend is actually doing, not just what this process asked
+//! it to do.
+
+use crate::backend_support::{check_recording_state, detect_running_backend_port};
+use crate::config::ServerMode;
+use log::info;
+use reqwest::Client;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+const RECORDING_STATE_EVENT: &str = "recording-state-changed";
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Last-known backend recording state.
+static RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// Whether the backend was last known to be recording.
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::Relaxed)
+}
+
+async fn resolve_port() -> u16 {
+    match detect_running_backend_port(ServerMode::current()).await {
+        Some(port) => port,
+        None => crate::backend::get_backend_port(),
+    }
+}
+
+/// Every pooled worker port the recording command needs to reach, falling
+/// back to whatever single port [`resolve_port`] finds when the pool isn't
+/// up yet (e.g. the backend is still starting).
+async fn worker_ports() -> Vec<u16> {
+    let ports = crate::backend::ready_worker_ports();
+    if !ports.is_empty() {
+        ports
+    } else {
+        vec![resolve_port().await]
+    }
+}
+
+async fn post_to(port: u16, path: &str) -> Result<(), String> {
+    let url = format!("http://127.0.0.1:{}{}", port, path);
+    let client = Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+    let response = client
+        .post(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Request to {} failed: {}", path, e))?;
+    if !response.status().is_success() {
+        return Err(format!("{} returned {}", path, response.status()));
+    }
+    Ok(())
+}
+
+/// POST `path` to every pooled worker, so a recording start/stop actually
+/// lands on the whole pool instead of whichever one worker the proxy would
+/// have routed a single request to.
+async fn post_all(path: &str) -> Result<(), String> {
+    let mut errors = Vec::new();
+    for port in worker_ports().await {
+        if let Err(err) = post_to(port, path).await {
+            errors.push(format!("worker {}: {}", port, err));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+/// POST `/recording/start` on every worker and update the tray/frontend
+/// state on success.
+pub async fn start_recording(app: &AppHandle) -> Result<(), String> {
+    post_all("/recording/start").await?;
+    set_state(app, true);
+    Ok(())
+}
+
+/// POST `/recording/stop` on every worker and update the tray/frontend
+/// state on success.
+pub async fn stop_recording(app: &AppHandle) -> Result<(), String> {
+    post_all("/recording/stop").await?;
+    set_state(app, false);
+    Ok(())
+}
+
+/// POST `/screenshot` on the worker the proxy would route to. Doesn't change
+/// the recording state.
+pub async fn take_screenshot(_app: &AppHandle) -> Result<(), String> {
+    post_to(resolve_port().await, "/screenshot").await
+}
+
+/// Start a background loop that polls the backend's `/health` for its
+/// `recording` field, updating the tray/frontend only when it differs from
+/// what we last knew. Call once from `lib.rs::run`.
+pub fn start_polling(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let port = resolve_port().await;
+            if let Some(recording) = check_recording_state(port).await {
+                set_state(&app, recording);
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+}
+
+fn set_state(app: &AppHandle, recording: bool) {
+    if RECORDING.swap(recording, Ordering::Relaxed) == recording {
+        return;
+    }
+    crate::tray::update_recording_menu(app, recording);
+    let _ = app.emit(RECORDING_STATE_EVENT, recording);
+    info!("Recording state changed: {}", recording);
+}
@@ -0,0 +1,50 @@
+//! Shared plumbing for the two outbound relay tunnels: `tunnel` (frontend +
+//! `/api`/`/backend` paths, single device-wide token) and `backend_tunnel`
+//! (backend only, a token carried on every forwarded request). Their relay
+//! message schemas differ enough - `tunnel`'s has a `Ping` keepalive,
+//! `backend_tunnel`'s carries a token per `ForwardRequest` - that merging the
+//! multiplex loops themselves would cost more clarity than it saves. What's
+//! actually duplicated, and extracted here, is the connect/register
+//! handshake and the per-device token generation both loops authenticate
+//! with.
+
+use futures_util::stream::{SplitSink, SplitStream};
+use rand::RngCore;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+pub type RelaySink = SplitSink<WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>, Message>;
+pub type RelayStream = SplitStream<WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>>;
+
+/// Generate a per-device relay token. `rand`'s default `thread_rng` is a
+/// CSPRNG seeded from OS entropy, unlike a timestamp+PID combination - which
+/// is predictable enough that anyone who can guess roughly when (and on
+/// which host) the app started could forge it.
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Dial `relay_url` and send the `{"type":"register","token":...}` handshake
+/// every relay channel expects, returning the split sink/stream for the
+/// caller's own multiplex loop.
+pub async fn connect_and_register(
+    relay_url: &str,
+    token: &str,
+) -> Result<(RelaySink, RelayStream), String> {
+    use futures_util::{SinkExt, StreamExt};
+
+    let (ws_stream, _) = tokio_tungstenite::connect_async(relay_url)
+        .await
+        .map_err(|e| format!("Failed to connect to relay: {}", e))?;
+    let (mut write, read) = ws_stream.split();
+
+    let register = serde_json::json!({ "type": "register", "token": token });
+    write
+        .send(Message::Text(register.to_string()))
+        .await
+        .map_err(|e| format!("Failed to register with relay: {}", e))?;
+
+    Ok((write, read))
+}
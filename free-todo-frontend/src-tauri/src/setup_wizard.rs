@@ -0,0 +1,215 @@
+//! First-run backend setup wizard.
+//!
+//! `get_backend_runtime()` used to only consult env vars and re-decide (and
+//! for the Script runtime, re-cascade through uv -> system-Python fallbacks)
+//! on every launch. This module probes the machine once for the dependencies
+//! each `BackendRuntime` needs, lets the user (or the caller) pick one, and
+//! persists that choice plus the resolved interpreter path into
+//! `lifetrace.toml` so `start_backend_process` can skip re-detection on
+//! subsequent starts.
+
+use crate::backend_paths::get_backend_path;
+use crate::backend_python::find_python312;
+use crate::config::{self, BackendRuntimeKind, ServerMode};
+use crate::backend_log::emit_backend_log;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::Command;
+use tauri::AppHandle;
+
+/// Detected status of a single backend-runtime dependency.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyStatus {
+    pub name: String,
+    pub available: bool,
+    pub detail: String,
+    pub remediation: Option<String>,
+}
+
+/// Full first-run (or "re-run diagnostics") report.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    pub uv: DependencyStatus,
+    pub python312: DependencyStatus,
+    pub pyinstaller: DependencyStatus,
+    pub recommended: BackendRuntimeKind,
+}
+
+fn probe_uv() -> DependencyStatus {
+    match Command::new("uv").arg("--version").output() {
+        Ok(output) if output.status.success() => DependencyStatus {
+            name: "uv".to_string(),
+            available: true,
+            detail: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+            remediation: None,
+        },
+        _ => DependencyStatus {
+            name: "uv".to_string(),
+            available: false,
+            detail: "uv not found on PATH".to_string(),
+            remediation: Some(
+                "Install uv from https://docs.astral.sh/uv/getting-started/installation/"
+                    .to_string(),
+            ),
+        },
+    }
+}
+
+fn probe_python312() -> (DependencyStatus, Option<PathBuf>) {
+    match find_python312() {
+        Some(path) => (
+            DependencyStatus {
+                name: "python3.12".to_string(),
+                available: true,
+                detail: format!("Found at {}", path.display()),
+                remediation: None,
+            },
+            Some(path),
+        ),
+        None => (
+            DependencyStatus {
+                name: "python3.12".to_string(),
+                available: false,
+                detail: "No Python 3.12 interpreter found on PATH".to_string(),
+                remediation: Some(
+                    "Install Python 3.12, or let LifeTrace download a portable build on first run"
+                        .to_string(),
+                ),
+            },
+            None,
+        ),
+    }
+}
+
+async fn probe_pyinstaller(app: &AppHandle) -> DependencyStatus {
+    match get_backend_path(app) {
+        Ok(path) => DependencyStatus {
+            name: "pyinstaller".to_string(),
+            available: true,
+            detail: format!("Bundled executable at {}", path.display()),
+            remediation: None,
+        },
+        Err(err) => DependencyStatus {
+            name: "pyinstaller".to_string(),
+            available: false,
+            detail: err,
+            remediation: Some(
+                "This build channel doesn't ship a bundled executable; use the uv or script runtime instead"
+                    .to_string(),
+            ),
+        },
+    }
+}
+
+fn recommend(uv: &DependencyStatus, python312: &DependencyStatus, pyinstaller: &DependencyStatus) -> BackendRuntimeKind {
+    if pyinstaller.available {
+        BackendRuntimeKind::Pyinstaller
+    } else if uv.available {
+        BackendRuntimeKind::Uv
+    } else if python312.available {
+        BackendRuntimeKind::Script
+    } else {
+        BackendRuntimeKind::Uv
+    }
+}
+
+fn log_status(app: &AppHandle, status: &DependencyStatus) {
+    if status.available {
+        emit_backend_log(app, format!("[setup] {}: {}", status.name, status.detail));
+    } else {
+        let remediation = status
+            .remediation
+            .clone()
+            .unwrap_or_else(|| "No remediation available".to_string());
+        emit_backend_log(
+            app,
+            format!(
+                "[setup] {}: not available ({}) - {}",
+                status.name, status.detail, remediation
+            ),
+        );
+    }
+}
+
+/// Probe the machine for every backend-runtime dependency, logging each
+/// result (with remediation steps for anything missing) through
+/// `emit_backend_log`. Safe to call repeatedly as a "re-run diagnostics"
+/// action.
+#[tauri::command]
+pub async fn run_backend_diagnostics(app: AppHandle) -> Result<DiagnosticsReport, String> {
+    let uv = probe_uv();
+    let (python312, _python_path) = probe_python312();
+    let pyinstaller = probe_pyinstaller(&app).await;
+    let recommended = recommend(&uv, &python312, &pyinstaller);
+
+    log_status(&app, &uv);
+    log_status(&app, &python312);
+    log_status(&app, &pyinstaller);
+    emit_backend_log(&app, format!("[setup] Recommended runtime: {:?}", recommended));
+
+    Ok(DiagnosticsReport {
+        uv,
+        python312,
+        pyinstaller,
+        recommended,
+    })
+}
+
+/// Whether the first-run wizard still needs to run (no runtime has been
+/// persisted to `lifetrace.toml` yet).
+#[tauri::command]
+pub fn needs_first_run_setup() -> bool {
+    config::current().backend_runtime.is_none()
+}
+
+/// Persist the chosen runtime (and, for the Script runtime, the resolved
+/// interpreter path) into `lifetrace.toml` and reload the active config so
+/// the very next backend start consumes it.
+#[tauri::command]
+pub fn select_backend_runtime(
+    app: AppHandle,
+    runtime: BackendRuntimeKind,
+    interpreter_path: Option<String>,
+) -> Result<(), String> {
+    let data_dir = crate::backend_paths::get_data_dir(&app, ServerMode::current())?;
+    let path = config::config_file_path(&data_dir);
+
+    let mut doc: toml::Value = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|text| toml::from_str(&text).ok())
+        .unwrap_or_else(|| toml::Value::Table(Default::default()));
+
+    let table = doc
+        .as_table_mut()
+        .ok_or_else(|| "lifetrace.toml is malformed (expected a table)".to_string())?;
+    let backend_table = table
+        .entry("backend")
+        .or_insert_with(|| toml::Value::Table(Default::default()))
+        .as_table_mut()
+        .ok_or_else(|| "lifetrace.toml [backend] section is malformed".to_string())?;
+
+    let runtime_str = match runtime {
+        BackendRuntimeKind::Uv => "uv",
+        BackendRuntimeKind::Script => "script",
+        BackendRuntimeKind::Pyinstaller => "pyinstaller",
+    };
+    backend_table.insert(
+        "runtime".to_string(),
+        toml::Value::String(runtime_str.to_string()),
+    );
+    if let Some(interpreter_path) = interpreter_path {
+        backend_table.insert(
+            "interpreter_path".to_string(),
+            toml::Value::String(interpreter_path),
+        );
+    }
+
+    let serialized =
+        toml::to_string_pretty(&doc).map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    std::fs::write(&path, serialized).map_err(|e| format!("Failed to write config: {}", e))?;
+
+    config::reload(&app, &data_dir);
+    emit_backend_log(&app, format!("[setup] Backend runtime set to {}", runtime_str));
+    Ok(())
+}
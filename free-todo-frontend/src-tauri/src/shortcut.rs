@@ -1,46 +1,46 @@
 //! Global Shortcut Management
 //!
-//! This module handles global keyboard shortcuts for the application,
-//! providing quick access to common functions from anywhere in the system.
-
+//! Shortcuts are config-driven: the `[shortcuts]` table in `lifetrace.toml`
+//! maps named actions (see [`ShortcutAction`]) to accelerator strings,
+//! loaded through the `config` module instead of a single hardcoded
+//! `toggle_window` binding. `update_shortcut` lets a binding be rebound at
+//! runtime - unregister the old accelerator, register the new one, and only
+//! persist the change once registration actually succeeds.
+
+use crate::actions::{self, ShortcutAction};
+use crate::config;
 use log::{error, info, warn};
-use tauri::{App, AppHandle, Manager};
+use tauri::{App, AppHandle};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
-/// Default shortcut configurations
-pub struct ShortcutConfig {
-    /// Toggle window visibility shortcut
-    pub toggle_window: &'static str,
-}
-
-impl Default for ShortcutConfig {
-    fn default() -> Self {
-        Self {
-            toggle_window: "CommandOrControl+Shift+I",
-        }
-    }
-}
-
-/// Setup global shortcuts
+/// Register every shortcut from the persisted `[shortcuts]` config.
 pub fn setup_shortcuts(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     info!("Setting up global shortcuts...");
 
-    let config = ShortcutConfig::default();
     let handle = app.handle().clone();
-
-    // Register toggle window shortcut
-    register_toggle_shortcut(&handle, config.toggle_window)?;
+    for (action_name, accelerator) in config::current().shortcuts {
+        if let Err(err) = register_shortcut(&handle, &action_name, &accelerator) {
+            warn!(
+                "Skipping shortcut {} ({}): {}",
+                action_name, accelerator, err
+            );
+        }
+    }
 
     info!("Global shortcuts registered successfully");
 
     Ok(())
 }
 
-/// Register the toggle window shortcut
-fn register_toggle_shortcut(
+/// Parse `accelerator`, look up `action_name` in the shared action registry,
+/// and register it to dispatch through [`actions::dispatch`].
+fn register_shortcut(
     app: &AppHandle,
+    action_name: &str,
     accelerator: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let action = ShortcutAction::parse(action_name)
+        .ok_or_else(|| format!("unknown shortcut action: {}", action_name))?;
     let shortcut: Shortcut = accelerator.parse()?;
 
     let app_handle = app.clone();
@@ -49,45 +49,16 @@ fn register_toggle_shortcut(
     app.global_shortcut()
         .on_shortcut(shortcut, move |_app, _shortcut, event| {
             if event.state == ShortcutState::Pressed {
-                info!("Toggle shortcut triggered: {}", accel_string);
-                toggle_window(&app_handle);
+                info!("Shortcut triggered: {} ({:?})", accel_string, action);
+                actions::dispatch(&app_handle, action);
             }
         })?;
 
-    info!("Registered shortcut: {} - Toggle Window", accelerator);
+    info!("Registered shortcut: {} -> {:?}", accelerator, action);
 
     Ok(())
 }
 
-/// Toggle main window visibility
-fn toggle_window(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        match window.is_visible() {
-            Ok(true) => {
-                if let Err(e) = window.hide() {
-                    error!("Failed to hide window: {}", e);
-                } else {
-                    info!("Window hidden via shortcut");
-                }
-            }
-            Ok(false) => {
-                if let Err(e) = window.show() {
-                    error!("Failed to show window: {}", e);
-                } else if let Err(e) = window.set_focus() {
-                    warn!("Failed to focus window: {}", e);
-                } else {
-                    info!("Window shown via shortcut");
-                }
-            }
-            Err(e) => {
-                error!("Failed to check window visibility: {}", e);
-            }
-        }
-    } else {
-        error!("Main window not found");
-    }
-}
-
 /// Unregister all shortcuts
 #[allow(dead_code)]
 pub fn unregister_all(app: &AppHandle) {
@@ -98,24 +69,42 @@ pub fn unregister_all(app: &AppHandle) {
     }
 }
 
-/// Update a shortcut with a new accelerator
+/// Rebind `action_name` to `new_accelerator`: unregister the current
+/// binding (if any), register the new one, and persist it to
+/// `lifetrace.toml`. If registering the new accelerator fails, the old one
+/// is re-registered so the user isn't left without a working shortcut, and
+/// the config file is left untouched.
 #[allow(dead_code)]
 pub fn update_shortcut(
     app: &AppHandle,
-    old_accelerator: &str,
+    action_name: &str,
     new_accelerator: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Unregister old shortcut
-    let old_shortcut: Shortcut = old_accelerator.parse()?;
-    app.global_shortcut().unregister(old_shortcut)?;
+    let old_accelerator = config::current().shortcuts.get(action_name).cloned();
 
-    // Register new shortcut
-    register_toggle_shortcut(app, new_accelerator)?;
+    if let Some(old) = &old_accelerator {
+        if let Ok(old_shortcut) = old.parse::<Shortcut>() {
+            let _ = app.global_shortcut().unregister(old_shortcut);
+        }
+    }
+
+    if let Err(err) = register_shortcut(app, action_name, new_accelerator) {
+        if let Some(old) = &old_accelerator {
+            if let Err(rollback_err) = register_shortcut(app, action_name, old) {
+                error!(
+                    "Failed to roll back shortcut {} to {}: {}",
+                    action_name, old, rollback_err
+                );
+            }
+        }
+        return Err(err);
+    }
+
+    if let Err(err) = config::persist_shortcut(app, action_name, new_accelerator) {
+        warn!("Shortcut {} updated but not persisted: {}", action_name, err);
+    }
 
-    info!(
-        "Shortcut updated from {} to {}",
-        old_accelerator, new_accelerator
-    );
+    info!("Shortcut {} updated to {}", action_name, new_accelerator);
 
     Ok(())
 }
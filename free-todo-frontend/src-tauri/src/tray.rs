@@ -5,14 +5,23 @@
 //!
 //! Note: Currently designed for Web mode. Island mode features are placeholders.
 
+use crate::actions::{self, ShortcutAction};
+use crate::autostart;
 use log::{error, info};
 use tauri::{
     image::Image,
-    menu::{Menu, MenuItem, PredefinedMenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
     App, AppHandle, Manager,
 };
 
+/// Id the tray icon is registered under, so `update_tray_tooltip` can look
+/// it back up via `Manager::tray_by_id`.
+const TRAY_ID: &str = "main-tray";
+
+/// Menu item id for the "Start on Login" checkbox.
+const START_ON_LOGIN_ID: &str = "start_on_login";
+
 /// Setup the system tray
 pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     info!("Setting up system tray...");
@@ -22,7 +31,7 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     // Create menu items
     let show_hide = MenuItem::with_id(
         handle,
-        "show_hide",
+        "toggle_window",
         "Show/Hide Window",
         true,
         Some("CmdOrCtrl+Shift+I"),
@@ -30,7 +39,11 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
 
     let separator1 = PredefinedMenuItem::separator(handle)?;
 
-    let recording_menu = create_recording_submenu(handle)?;
+    let (recording_menu, start_recording, stop_recording) = create_recording_submenu(handle)?;
+    app.manage(RecordingMenuItems {
+        start: start_recording,
+        stop: stop_recording,
+    });
     let screenshot_menu = create_screenshot_submenu(handle)?;
 
     let separator2 = PredefinedMenuItem::separator(handle)?;
@@ -38,6 +51,16 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     let preferences =
         MenuItem::with_id(handle, "preferences", "Preferences...", true, None::<&str>)?;
 
+    let start_on_login = CheckMenuItem::with_id(
+        handle,
+        START_ON_LOGIN_ID,
+        "Start on Login",
+        true,
+        autostart::get_start_on_login(),
+        None::<&str>,
+    )?;
+    app.manage(start_on_login.clone());
+
     let separator3 = PredefinedMenuItem::separator(handle)?;
 
     let quit = MenuItem::with_id(handle, "quit", "Quit FreeTodo", true, Some("CmdOrCtrl+Q"))?;
@@ -52,6 +75,7 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
             &screenshot_menu,
             &separator2,
             &preferences,
+            &start_on_login,
             &separator3,
             &quit,
         ],
@@ -61,7 +85,7 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     let icon = get_tray_icon(app)?;
 
     // Create tray icon
-    let _tray = TrayIconBuilder::new()
+    let _tray = TrayIconBuilder::with_id(TRAY_ID)
         .icon(icon)
         .menu(&menu)
         .tooltip("FreeTodo - Dynamic Island")
@@ -78,15 +102,31 @@ pub fn setup_tray(app: &App) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-/// Create recording submenu
+/// State of the two recording menu items, managed so `update_recording_menu`
+/// can toggle their enabled flag from `recording::set_state`.
+struct RecordingMenuItems {
+    start: MenuItem<tauri::Wry>,
+    stop: MenuItem<tauri::Wry>,
+}
+
+/// Create recording submenu. Starts with "Start Recording" enabled and
+/// "Stop Recording" disabled, matching the initial (not-recording) state;
+/// `update_recording_menu` flips this once the backend reports otherwise.
 fn create_recording_submenu(
     handle: &AppHandle,
-) -> Result<tauri::menu::Submenu<tauri::Wry>, tauri::Error> {
+) -> Result<
+    (
+        tauri::menu::Submenu<tauri::Wry>,
+        MenuItem<tauri::Wry>,
+        MenuItem<tauri::Wry>,
+    ),
+    tauri::Error,
+> {
     let start_recording = MenuItem::with_id(
         handle,
         "start_recording",
         "Start Recording",
-        false,
+        true,
         None::<&str>,
     )?;
     let stop_recording = MenuItem::with_id(
@@ -97,12 +137,13 @@ fn create_recording_submenu(
         None::<&str>,
     )?;
 
-    tauri::menu::Submenu::with_items(
+    let submenu = tauri::menu::Submenu::with_items(
         handle,
         "Recording",
         true,
         &[&start_recording, &stop_recording],
-    )
+    )?;
+    Ok((submenu, start_recording, stop_recording))
 }
 
 /// Create screenshot submenu
@@ -113,7 +154,7 @@ fn create_screenshot_submenu(
         handle,
         "take_screenshot",
         "Take Screenshot",
-        false,
+        true,
         None::<&str>,
     )?;
     let view_screenshots = MenuItem::with_id(
@@ -165,38 +206,38 @@ fn get_tray_icon(_app: &App) -> Result<Image<'static>, Box<dyn std::error::Error
     Ok(Image::new_owned(rgba, info.width, info.height))
 }
 
-/// Handle menu item click events
+/// Handle menu item click events by dispatching through the same action
+/// handler the global shortcuts use.
 fn handle_menu_event(app: &AppHandle, menu_id: &str) {
     info!("Menu event: {}", menu_id);
 
     match menu_id {
-        "show_hide" => {
-            toggle_window(app);
-        }
         "preferences" => {
             // Show preferences (for now, just show window)
-            show_window(app);
+            actions::dispatch(app, ShortcutAction::ShowWindow);
             info!("Preferences clicked - feature not yet implemented");
         }
         "quit" => {
             info!("Quit requested from tray menu");
             app.exit(0);
         }
-        "start_recording" => {
-            info!("Start recording - feature not yet implemented");
-        }
-        "stop_recording" => {
-            info!("Stop recording - feature not yet implemented");
-        }
-        "take_screenshot" => {
-            info!("Take screenshot - feature not yet implemented");
-        }
-        "view_screenshots" => {
-            info!("View screenshots - feature not yet implemented");
-        }
-        _ => {
-            info!("Unknown menu event: {}", menu_id);
+        START_ON_LOGIN_ID => {
+            let enabled = !autostart::get_start_on_login();
+            match autostart::set_start_on_login(app.clone(), enabled) {
+                Ok(()) => {
+                    if let Some(item) = app.try_state::<CheckMenuItem<tauri::Wry>>() {
+                        if let Err(e) = item.set_checked(enabled) {
+                            error!("Failed to update Start on Login checkbox: {}", e);
+                        }
+                    }
+                }
+                Err(e) => error!("Failed to toggle start on login: {}", e),
+            }
         }
+        _ => match ShortcutAction::parse(menu_id) {
+            Some(action) => actions::dispatch(app, action),
+            None => info!("Unknown menu event: {}", menu_id),
+        },
     }
 }
 
@@ -209,66 +250,45 @@ fn handle_tray_event(app: &AppHandle, event: TrayIconEvent) {
             ..
         } => {
             info!("Tray icon left-clicked");
-            toggle_window(app);
+            actions::dispatch(app, ShortcutAction::ToggleWindow);
         }
         TrayIconEvent::DoubleClick {
             button: MouseButton::Left,
             ..
         } => {
             info!("Tray icon double-clicked");
-            show_window(app);
+            actions::dispatch(app, ShortcutAction::ShowWindow);
         }
         _ => {}
     }
 }
 
-/// Toggle main window visibility
-fn toggle_window(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        match window.is_visible() {
-            Ok(true) => {
-                let _ = window.hide();
-                info!("Window hidden");
-            }
-            Ok(false) => {
-                let _ = window.show();
-                let _ = window.set_focus();
-                info!("Window shown");
-            }
-            Err(e) => {
-                error!("Failed to check window visibility: {}", e);
-            }
-        }
+/// Update the tray tooltip to reflect current window visibility. Called by
+/// `visibility` after every show/hide/toggle.
+pub fn update_tray_tooltip(app: &AppHandle, visible: bool) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let tooltip = if visible {
+        "FreeTodo - Dynamic Island (visible)"
     } else {
-        error!("Main window not found");
+        "FreeTodo - Dynamic Island (hidden)"
+    };
+    if let Err(e) = tray.set_tooltip(Some(tooltip)) {
+        error!("Failed to update tray tooltip: {}", e);
     }
 }
 
-/// Show main window
-fn show_window(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let _ = window.show();
-        let _ = window.set_focus();
-        info!("Window shown and focused");
+/// Toggle the recording submenu's enabled state to match the backend's
+/// actual recording status. Called by `recording::set_state`.
+pub fn update_recording_menu(app: &AppHandle, recording: bool) {
+    let Some(items) = app.try_state::<RecordingMenuItems>() else {
+        return;
+    };
+    if let Err(e) = items.start.set_enabled(!recording) {
+        error!("Failed to update Start Recording menu item: {}", e);
     }
-}
-
-/// Hide main window
-#[allow(dead_code)]
-fn hide_window(app: &AppHandle) {
-    if let Some(window) = app.get_webview_window("main") {
-        let _ = window.hide();
-        info!("Window hidden");
+    if let Err(e) = items.stop.set_enabled(recording) {
+        error!("Failed to update Stop Recording menu item: {}", e);
     }
 }
-
-/// Update tray tooltip based on window state
-#[allow(dead_code)]
-pub fn update_tray_tooltip(_app: &AppHandle, visible: bool) {
-    // Tray tooltip update would be implemented here
-    // Currently Tauri 2.x doesn't have a direct API for updating tooltip after creation
-    info!(
-        "Tray state updated: Window is {}",
-        if visible { "visible" } else { "hidden" }
-    );
-}
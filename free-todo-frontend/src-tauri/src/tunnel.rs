@@ -0,0 +1,212 @@
+//! Secure relay tunnel for remote access to the local app.
+//!
+//! Mirrors the model used by VS Code's `code-tunnel`: the app opens an
+//! authenticated outbound WebSocket connection to a relay server, registers
+//! itself, and the relay hands back a public URL that forwards HTTP
+//! requests back down the socket to be served locally.
+
+use crate::nextjs;
+use crate::relay;
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Relay server to dial, overridable for self-hosted relays.
+fn relay_url() -> String {
+    std::env::var("FREETODO_RELAY_URL")
+        .unwrap_or_else(|_| "wss://relay.freetodo.app/tunnel".to_string())
+}
+
+static TUNNEL_ENABLED: AtomicBool = AtomicBool::new(false);
+static SHUTDOWN: Mutex<Option<oneshot::Sender<()>>> = Mutex::new(None);
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RelayMessage {
+    Registered { public_url: String },
+    ForwardRequest {
+        id: String,
+        method: String,
+        path: String,
+        #[serde(default)]
+        body: String,
+    },
+    Ping,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RelayReply {
+    ForwardResponse {
+        id: String,
+        status: u16,
+        body: String,
+    },
+    Pong,
+}
+
+fn token_path() -> Result<std::path::PathBuf, String> {
+    let dir = dirs_next_config_dir()?;
+    Ok(dir.join("tunnel-token"))
+}
+
+/// Tiny stand-in for a platform config-dir lookup; avoids pulling in the
+/// `dirs` crate just for one file.
+fn dirs_next_config_dir() -> Result<std::path::PathBuf, String> {
+    let home = std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .map_err(|_| "Could not determine home directory".to_string())?;
+    let dir = std::path::PathBuf::from(home).join(".freetodo");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    Ok(dir)
+}
+
+/// Load the persisted per-device tunnel token, generating and saving a new
+/// one on first use.
+fn get_or_create_device_token() -> Result<String, String> {
+    let path = token_path()?;
+    if let Ok(existing) = std::fs::read_to_string(&path) {
+        let trimmed = existing.trim().to_string();
+        if !trimmed.is_empty() {
+            return Ok(trimmed);
+        }
+    }
+
+    let token = relay::generate_token();
+    std::fs::write(&path, &token).map_err(|e| format!("Failed to persist tunnel token: {}", e))?;
+    Ok(token)
+}
+
+/// Resolve which local URL a forwarded request should be served from, based
+/// on a simple path-prefix convention shared with the proxy.
+fn target_url_for(path: &str) -> String {
+    if path.starts_with("/api") || path.starts_with("/backend") {
+        format!("{}{}", crate::backend::get_backend_url(), path)
+    } else {
+        format!("{}{}", nextjs::get_frontend_url(), path)
+    }
+}
+
+/// Open the tunnel and start forwarding traffic. No-op if already running.
+pub async fn start_tunnel(app: tauri::AppHandle) -> Result<(), String> {
+    if TUNNEL_ENABLED.swap(true, Ordering::SeqCst) {
+        return Ok(());
+    }
+
+    let token = get_or_create_device_token()?;
+    let (shutdown_tx, mut shutdown_rx) = oneshot::channel();
+    {
+        let mut guard = SHUTDOWN.lock().unwrap();
+        *guard = Some(shutdown_tx);
+    }
+
+    tokio::spawn(async move {
+        loop {
+            if !TUNNEL_ENABLED.load(Ordering::SeqCst) {
+                break;
+            }
+
+            match run_tunnel_session(&token, &mut shutdown_rx).await {
+                Ok(()) => break,
+                Err(err) => {
+                    warn!("Tunnel session ended: {}", err);
+                    crate::backend_log::emit_backend_log(
+                        &app,
+                        format!("Tunnel disconnected: {}, reconnecting...", err),
+                    );
+                }
+            }
+
+            if tokio::time::timeout(Duration::from_secs(5), &mut shutdown_rx)
+                .await
+                .is_ok()
+            {
+                break;
+            }
+        }
+        TUNNEL_ENABLED.store(false, Ordering::SeqCst);
+    });
+
+    Ok(())
+}
+
+async fn run_tunnel_session(
+    token: &str,
+    shutdown_rx: &mut oneshot::Receiver<()>,
+) -> Result<(), String> {
+    let (mut write, mut read) = relay::connect_and_register(&relay_url(), token).await?;
+
+    let http_client = reqwest::Client::new();
+
+    loop {
+        let message = tokio::select! {
+            _ = &mut *shutdown_rx => break,
+            message = read.next() => message,
+        };
+        let Some(message) = message else { break };
+        let message = message.map_err(|e| format!("Relay connection error: {}", e))?;
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+
+        let parsed: RelayMessage = match serde_json::from_str(&text) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                warn!("Ignoring unrecognized relay message: {}", err);
+                continue;
+            }
+        };
+
+        match parsed {
+            RelayMessage::Registered { public_url } => {
+                info!("Tunnel registered, reachable at {}", public_url);
+            }
+            RelayMessage::Ping => {
+                let pong = serde_json::to_string(&RelayReply::Pong).unwrap_or_default();
+                let _ = write.send(Message::Text(pong)).await;
+            }
+            RelayMessage::ForwardRequest { id, method, path, body } => {
+                let url = target_url_for(&path);
+                let method = reqwest::Method::from_bytes(method.as_bytes())
+                    .unwrap_or(reqwest::Method::GET);
+                let (status, body) = match http_client.request(method, &url).body(body).send().await {
+                    Ok(response) => {
+                        let status = response.status().as_u16();
+                        let body = response.text().await.unwrap_or_default();
+                        (status, body)
+                    }
+                    Err(err) => (502, format!("tunnel forward failed: {}", err)),
+                };
+                let reply = RelayReply::ForwardResponse { id, status, body };
+                if let Ok(payload) = serde_json::to_string(&reply) {
+                    let _ = write.send(Message::Text(payload)).await;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Tear down the tunnel, if running.
+pub fn stop_tunnel() {
+    if !TUNNEL_ENABLED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+    if let Some(sender) = SHUTDOWN.lock().unwrap().take() {
+        let _ = sender.send(());
+    }
+    info!("Tunnel stopped");
+}
+
+/// Cleanup on application exit, mirroring `nextjs::cleanup`/`backend::cleanup`.
+pub fn cleanup() {
+    stop_tunnel();
+}
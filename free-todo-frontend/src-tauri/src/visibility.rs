@@ -0,0 +1,93 @@
+//! Unified window-visibility subsystem.
+//!
+//! Window show/hide/toggle used to be implemented three times - once each in
+//! `lib.rs`'s commands, `shortcut::toggle_window`, and `tray`'s click
+//! handlers - with slightly different logging and error handling, and no
+//! way for the frontend to know the window had changed state. This module
+//! is the single owner: it performs the actual show/hide/toggle against
+//! whichever window `window_mode` says is active, tracks the result in
+//! managed [`WindowState`], refreshes the tray tooltip, and emits a
+//! `window-visibility-changed` event.
+
+use crate::window_mode;
+use log::{error, info};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+
+const VISIBILITY_EVENT: &str = "window-visibility-changed";
+
+/// The window `toggle`/`show`/`hide` currently act on, per `window_mode`.
+fn active_window() -> &'static str {
+    window_mode::window_label(crate::config::current().window_mode)
+}
+
+/// Last-known visibility of the active window, so callers don't each need to
+/// re-query `Window::is_visible()`.
+pub struct WindowState {
+    visible: AtomicBool,
+}
+
+impl Default for WindowState {
+    fn default() -> Self {
+        Self {
+            visible: AtomicBool::new(true),
+        }
+    }
+}
+
+/// Register [`WindowState`] with the app. Call once during setup, before
+/// any of `toggle`/`show`/`hide` are used.
+pub fn init(app: &AppHandle) {
+    app.manage(WindowState::default());
+}
+
+/// Show the active window if hidden, hide it if visible.
+pub fn toggle(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(active_window()) else {
+        error!("Active window ({}) not found", active_window());
+        return;
+    };
+    match window.is_visible() {
+        Ok(true) => hide(app),
+        Ok(false) => show(app),
+        Err(e) => error!("Failed to check window visibility: {}", e),
+    }
+}
+
+/// Show and focus the active window.
+pub fn show(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(active_window()) else {
+        error!("Active window ({}) not found", active_window());
+        return;
+    };
+    if let Err(e) = window.show() {
+        error!("Failed to show window: {}", e);
+        return;
+    }
+    if let Err(e) = window.set_focus() {
+        error!("Failed to focus window: {}", e);
+    }
+    on_visibility_changed(app, true);
+}
+
+/// Hide the active window.
+pub fn hide(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(active_window()) else {
+        error!("Active window ({}) not found", active_window());
+        return;
+    };
+    if let Err(e) = window.hide() {
+        error!("Failed to hide window: {}", e);
+        return;
+    }
+    on_visibility_changed(app, false);
+}
+
+fn on_visibility_changed(app: &AppHandle, visible: bool) {
+    if let Some(state) = app.try_state::<WindowState>() {
+        state.visible.store(visible, Ordering::Relaxed);
+    }
+    crate::tray::update_tray_tooltip(app, visible);
+    let _ = app.emit(VISIBILITY_EVENT, visible);
+    info!("Window {}", if visible { "shown" } else { "hidden" });
+}
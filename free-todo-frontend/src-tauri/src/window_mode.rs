@@ -0,0 +1,106 @@
+//! Window-mode switching.
+//!
+//! Web mode uses the standard decorated "main" window (defined in
+//! `tauri.conf.json`). Island mode uses a second, borderless/transparent/
+//! always-on-top "island" window docked near the top of the screen, Dynamic
+//! Island style. Both windows are created during `.setup(...)`; only the
+//! active mode's window is ever shown, and `visibility`'s show/hide/toggle
+//! are routed to whichever one that is (see [`window_label`]).
+
+use crate::config;
+use crate::{nextjs, visibility, WindowMode};
+use log::info;
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+/// Name of the standard, decorated window (matches `tauri.conf.json`).
+pub const MAIN_WINDOW: &str = "main";
+/// Name of the borderless Island-mode window.
+pub const ISLAND_WINDOW: &str = "island";
+
+const ISLAND_WIDTH: f64 = 420.0;
+const ISLAND_HEIGHT: f64 = 60.0;
+const ISLAND_TOP_MARGIN: f64 = 12.0;
+
+/// The webview window backing a given mode.
+pub(crate) fn window_label(mode: WindowMode) -> &'static str {
+    match mode {
+        WindowMode::Web => MAIN_WINDOW,
+        WindowMode::Island => ISLAND_WINDOW,
+    }
+}
+
+/// Create the island window (hidden) and show whichever window the
+/// currently-loaded config selects. Call once from `lib.rs::run`'s
+/// `.setup(...)`, after the config has been loaded.
+pub fn init(app: &AppHandle) -> tauri::Result<()> {
+    ensure_island_window(app)?;
+
+    let mode = config::current().window_mode;
+    let inactive = window_label(match mode {
+        WindowMode::Web => WindowMode::Island,
+        WindowMode::Island => WindowMode::Web,
+    });
+    if let Some(window) = app.get_webview_window(inactive) {
+        let _ = window.hide();
+    }
+    if matches!(mode, WindowMode::Island) {
+        visibility::show(app);
+    }
+    Ok(())
+}
+
+/// Switch to `mode`: persist it, hide the previously-active window, and
+/// show the newly-active one.
+#[tauri::command]
+pub fn set_window_mode(app: AppHandle, mode: WindowMode) -> Result<(), String> {
+    let previous = config::current().window_mode;
+    if previous == mode {
+        return Ok(());
+    }
+
+    if matches!(mode, WindowMode::Island) {
+        ensure_island_window(&app).map_err(|e| format!("Failed to create island window: {}", e))?;
+    }
+
+    config::persist_window_mode(&app, mode)?;
+
+    if let Some(window) = app.get_webview_window(window_label(previous)) {
+        let _ = window.hide();
+    }
+    visibility::show(&app);
+
+    info!("Window mode switched to {:?}", mode);
+    Ok(())
+}
+
+/// Build the island window if it doesn't already exist. Idempotent, so it's
+/// safe to call both at startup and from `set_window_mode`.
+fn ensure_island_window(app: &AppHandle) -> tauri::Result<()> {
+    if app.get_webview_window(ISLAND_WINDOW).is_some() {
+        return Ok(());
+    }
+
+    let url = format!("{}/island", nextjs::get_frontend_url());
+    let island_url = WebviewUrl::External(url.parse().expect("frontend URL is always valid"));
+
+    let mut builder = WebviewWindowBuilder::new(app, ISLAND_WINDOW, island_url)
+        .title("FreeTodo")
+        .decorations(false)
+        .transparent(true)
+        .always_on_top(true)
+        .skip_taskbar(true)
+        .resizable(false)
+        .inner_size(ISLAND_WIDTH, ISLAND_HEIGHT)
+        .visible(false);
+
+    if let Ok(Some(monitor)) = app.primary_monitor() {
+        let scale = monitor.scale_factor();
+        let screen_width = monitor.size().width as f64 / scale;
+        let x = ((screen_width - ISLAND_WIDTH) / 2.0).max(0.0);
+        builder = builder.position(x, ISLAND_TOP_MARGIN);
+    }
+
+    builder.build()?;
+    info!("Island window created");
+    Ok(())
+}